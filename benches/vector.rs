@@ -0,0 +1,43 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_data_structures::vector::Vector;
+
+const LEN: usize = 1_000_000;
+
+fn resize_u8(c: &mut Criterion) {
+    let mut group = c.benchmark_group("resize_u8_1m");
+
+    group.bench_function("resize", |b| {
+        b.iter(|| {
+            let mut vec: Vector<u8> = Vector::new();
+            vec.resize(black_box(LEN), 0);
+            vec
+        })
+    });
+
+    group.bench_function("resize_zeroed", |b| {
+        b.iter(|| {
+            let mut vec: Vector<u8> = Vector::new();
+            vec.resize_zeroed(black_box(LEN));
+            vec
+        })
+    });
+
+    group.finish();
+}
+
+fn push_10m(c: &mut Criterion) {
+    // Growth is 1.5x per reallocation, so the final capacity stays closer to
+    // the length than with doubling
+    c.bench_function("push_u32_10m", |b| {
+        b.iter(|| {
+            let mut vec: Vector<u32> = Vector::new();
+            for i in 0..black_box(10_000_000u32) {
+                vec.push(i);
+            }
+            vec
+        })
+    });
+}
+
+criterion_group!(benches, resize_u8, push_10m);
+criterion_main!(benches);
@@ -0,0 +1,45 @@
+// no_std_smoke
+// Builds rust_data_structures without its `std` feature
+
+//! A `#![no_std]` crate exercising the collections that only need `alloc`.
+//!
+//! Build it on its own so that the `std` feature is not unified in from the
+//! rest of the workspace:
+//!
+//! ```bash
+//! cargo build -p no_std_smoke
+//! cargo build -p no_std_smoke --target thumbv7em-none-eabihf
+//! ```
+
+#![no_std]
+
+use rust_data_structures::{LinkedList, Vector};
+
+/// Pushes and pops through a `Vector` and a `LinkedList`, returning the sum
+/// of the popped elements
+pub fn push_and_pop() -> i32 {
+    let mut vec = Vector::new();
+    vec.push(1);
+    vec.push(2);
+
+    let mut list = LinkedList::new();
+    list.push_back(3);
+    list.push_front(4);
+
+    let mut sum = 0;
+    while let Some(x) = vec.pop() {
+        sum += x;
+    }
+    while let Some(x) = list.pop_front() {
+        sum += x;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn push_and_pop() {
+        assert_eq!(super::push_and_pop(), 10);
+    }
+}
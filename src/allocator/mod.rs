@@ -0,0 +1,206 @@
+//! Pluggable memory allocators
+//!
+//! This module provides a minimal allocator interface that containers can be
+//! parameterized over, so that they can be backed by arenas, bump allocators,
+//! or instrumented allocators instead of the global heap.
+
+use ::alloc::alloc::{self, Layout};
+use core::fmt;
+use core::ptr::{self, NonNull};
+
+/// The error returned when an allocator fails to satisfy a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocError;
+
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")
+    }
+}
+
+impl core::error::Error for AllocError {}
+
+/// A memory allocator that containers can allocate their buffers from
+///
+/// # Safety
+///
+/// Memory blocks returned by an allocator must point to valid memory of at
+/// least the requested size and alignment, and must stay valid until they
+/// are passed to [`deallocate`] or [`grow`] on the same allocator (or a
+/// clone of it).
+///
+/// [`deallocate`]: Allocator::deallocate
+/// [`grow`]: Allocator::grow
+pub unsafe trait Allocator {
+    /// Allocates a block of memory described by `layout`
+    ///
+    /// `layout` always has a non-zero size.
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError>;
+
+    /// Deallocates the block of memory at `ptr`
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by this allocator with `layout`.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+
+    /// Grows the block of memory at `ptr` to `new_layout`
+    ///
+    /// On success the contents of the old block are preserved and the old
+    /// block must no longer be used. On failure the old block is untouched.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by this allocator with `old_layout`,
+    /// and `new_layout.size()` must be at least `old_layout.size()`.
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        let new_ptr = self.allocate(new_layout)?;
+        ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), old_layout.size());
+        self.deallocate(ptr, old_layout);
+        Ok(new_ptr)
+    }
+
+    /// Shrinks the block of memory at `ptr` to `new_layout`
+    ///
+    /// On success the first `new_layout.size()` bytes of the old block are
+    /// preserved and the old block must no longer be used. On failure the
+    /// old block is untouched.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by this allocator with `old_layout`,
+    /// and `new_layout.size()` must be non-zero and at most
+    /// `old_layout.size()`.
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        let new_ptr = self.allocate(new_layout)?;
+        ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), new_layout.size());
+        self.deallocate(ptr, old_layout);
+        Ok(new_ptr)
+    }
+}
+
+unsafe impl<A: Allocator + ?Sized> Allocator for &A {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        (**self).allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        (**self).deallocate(ptr, layout)
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        (**self).grow(ptr, old_layout, new_layout)
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        (**self).shrink(ptr, old_layout, new_layout)
+    }
+}
+
+/// The global memory allocator
+///
+/// This forwards every request to the allocator registered with
+/// `#[global_allocator]`, or the system allocator by default.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Global;
+
+unsafe impl Allocator for Global {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        // `alloc::alloc` is undefined for zero-sized layouts
+        debug_assert_ne!(layout.size(), 0);
+        NonNull::new(unsafe { alloc::alloc(layout) }).ok_or(AllocError)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        alloc::dealloc(ptr.as_ptr(), layout)
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        debug_assert_eq!(old_layout.align(), new_layout.align());
+        NonNull::new(alloc::realloc(ptr.as_ptr(), old_layout, new_layout.size()))
+            .ok_or(AllocError)
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        debug_assert_eq!(old_layout.align(), new_layout.align());
+        NonNull::new(alloc::realloc(ptr.as_ptr(), old_layout, new_layout.size()))
+            .ok_or(AllocError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_global_grow_preserves_contents() {
+        let old_layout = Layout::array::<u32>(2).unwrap();
+        let new_layout = Layout::array::<u32>(8).unwrap();
+
+        unsafe {
+            let ptr = Global.allocate(old_layout).unwrap().cast::<u32>();
+            ptr.as_ptr().write(7);
+            ptr.as_ptr().add(1).write(9);
+
+            let grown = Global
+                .grow(ptr.cast(), old_layout, new_layout)
+                .unwrap()
+                .cast::<u32>();
+            assert_eq!(grown.as_ptr().read(), 7);
+            assert_eq!(grown.as_ptr().add(1).read(), 9);
+
+            Global.deallocate(grown.cast(), new_layout);
+        }
+    }
+
+    #[test]
+    fn test_global_shrink_preserves_contents() {
+        let old_layout = Layout::array::<u32>(8).unwrap();
+        let new_layout = Layout::array::<u32>(2).unwrap();
+
+        unsafe {
+            let ptr = Global.allocate(old_layout).unwrap().cast::<u32>();
+            ptr.as_ptr().write(7);
+            ptr.as_ptr().add(1).write(9);
+
+            let shrunk = Global
+                .shrink(ptr.cast(), old_layout, new_layout)
+                .unwrap()
+                .cast::<u32>();
+            assert_eq!(shrunk.as_ptr().read(), 7);
+            assert_eq!(shrunk.as_ptr().add(1).read(), 9);
+
+            Global.deallocate(shrunk.cast(), new_layout);
+        }
+    }
+}
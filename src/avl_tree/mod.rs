@@ -0,0 +1,600 @@
+//! An AVL tree implementation
+//!
+//! This module provides an ordered set stored as a self-balancing binary
+//! search tree. Each node tracks the height of its subtree, and rotations
+//! after every insertion and removal keep the heights of sibling subtrees
+//! within one of each other.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Bound, RangeBounds};
+
+// An owned, possibly empty subtree
+type Link<T> = Option<Box<AvlNode<T>>>;
+
+// A node in the tree
+struct AvlNode<T> {
+    value: T,
+    // Height of the subtree rooted here; a leaf has height 1
+    height: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T> AvlNode<T> {
+    fn new(value: T) -> Box<Self> {
+        Box::new(AvlNode {
+            value,
+            height: 1,
+            left: None,
+            right: None,
+        })
+    }
+
+    // Recomputes this node's height from its children
+    fn update_height(&mut self) {
+        self.height = 1 + height(&self.left).max(height(&self.right));
+    }
+
+    // Left subtree height minus right subtree height
+    fn balance_factor(&self) -> isize {
+        height(&self.left) as isize - height(&self.right) as isize
+    }
+}
+
+// Returns the height of a possibly empty subtree
+fn height<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+// Rotates the subtree right, lifting the left child into the root
+fn rotate_right<T>(mut node: Box<AvlNode<T>>) -> Box<AvlNode<T>> {
+    let mut pivot = node.left.take().expect("right rotation needs a left child");
+    node.left = pivot.right.take();
+    node.update_height();
+    pivot.right = Some(node);
+    pivot.update_height();
+    pivot
+}
+
+// Rotates the subtree left, lifting the right child into the root
+fn rotate_left<T>(mut node: Box<AvlNode<T>>) -> Box<AvlNode<T>> {
+    let mut pivot = node.right.take().expect("left rotation needs a right child");
+    node.right = pivot.left.take();
+    node.update_height();
+    pivot.left = Some(node);
+    pivot.update_height();
+    pivot
+}
+
+// Restores the AVL invariant at a node whose children are balanced but may
+// differ in height by two, and updates its height
+fn rebalance<T>(mut node: Box<AvlNode<T>>) -> Box<AvlNode<T>> {
+    node.update_height();
+    let balance = node.balance_factor();
+
+    if balance > 1 {
+        // Left-right case: straighten the left child first
+        if node.left.as_ref().is_some_and(|left| left.balance_factor() < 0) {
+            node.left = node.left.take().map(rotate_left);
+        }
+        rotate_right(node)
+    } else if balance < -1 {
+        // Right-left case: straighten the right child first
+        if node.right.as_ref().is_some_and(|right| right.balance_factor() > 0) {
+            node.right = node.right.take().map(rotate_right);
+        }
+        rotate_left(node)
+    } else {
+        node
+    }
+}
+
+/// An ordered set stored as a height-balanced binary search tree
+///
+/// Insertion, removal and lookup are all O(log n) in the worst case, since
+/// the height of a tree with `n` elements never exceeds about `1.44 log2 n`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::avl_tree::AVLTree;
+///
+/// let mut tree = AVLTree::new();
+/// tree.insert(3);
+/// tree.insert(1);
+/// tree.insert(2);
+///
+/// assert!(tree.contains(&2));
+/// assert_eq!(tree.iter().collect::<Vec<_>>(), [&1, &2, &3]);
+///
+/// assert!(tree.remove(&2));
+/// assert!(!tree.contains(&2));
+/// ```
+pub struct AVLTree<T: Ord> {
+    root: Link<T>,
+    len: usize,
+}
+
+impl<T: Ord> AVLTree<T> {
+    /// Creates a new, empty tree
+    pub fn new() -> Self {
+        AVLTree { root: None, len: 0 }
+    }
+
+    /// Returns the number of elements in the tree
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the tree is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the height of the tree; an empty tree has height 0
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// Adds a value to the tree
+    ///
+    /// Returns whether the value was newly inserted. If an equal value was
+    /// already present, the tree is left unchanged.
+    pub fn insert(&mut self, value: T) -> bool {
+        let (root, inserted) = Self::insert_at(self.root.take(), value);
+        self.root = Some(root);
+        if inserted {
+            self.len += 1;
+        }
+        inserted
+    }
+
+    /// Returns whether the tree contains `value`
+    pub fn contains(&self, value: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            link = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    /// Removes a value from the tree, returning whether it was present
+    pub fn remove(&mut self, value: &T) -> bool {
+        let (root, removed) = Self::remove_at(self.root.take(), value);
+        self.root = root;
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed.is_some()
+    }
+
+    /// Returns the smallest element in the tree
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = &node.left {
+            node = left;
+        }
+        Some(&node.value)
+    }
+
+    /// Returns the largest element in the tree
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = &node.right {
+            node = right;
+        }
+        Some(&node.value)
+    }
+
+    /// Returns an iterator over the elements within `range`, in ascending
+    /// order
+    ///
+    /// Subtrees lying entirely outside the range are skipped, so iterating
+    /// `k` elements takes O(log n + k) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::avl_tree::AVLTree;
+    ///
+    /// let tree: AVLTree<i32> = (0..10).collect();
+    /// let values: Vec<i32> = tree.range(3..=7).copied().collect();
+    /// assert_eq!(values, [3, 4, 5, 6, 7]);
+    /// ```
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> impl Iterator<Item = &T> {
+        let mut iter = Range {
+            stack: Vec::new(),
+            range,
+        };
+        iter.push_left(&self.root);
+        iter
+    }
+
+    /// Removes every element from the tree
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the elements in ascending order
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            remaining: self.len,
+        };
+        iter.push_left(&self.root);
+        iter
+    }
+
+    // Private method to insert into a subtree, returning the rebalanced
+    // subtree and whether the value was new
+    fn insert_at(link: Link<T>, value: T) -> (Box<AvlNode<T>>, bool) {
+        let Some(mut node) = link else {
+            return (AvlNode::new(value), true);
+        };
+
+        let inserted = match value.cmp(&node.value) {
+            Ordering::Less => {
+                let (left, inserted) = Self::insert_at(node.left.take(), value);
+                node.left = Some(left);
+                inserted
+            }
+            Ordering::Greater => {
+                let (right, inserted) = Self::insert_at(node.right.take(), value);
+                node.right = Some(right);
+                inserted
+            }
+            Ordering::Equal => return (node, false),
+        };
+        (rebalance(node), inserted)
+    }
+
+    // Private method to remove from a subtree, returning the rebalanced
+    // subtree and the removed value
+    fn remove_at(link: Link<T>, value: &T) -> (Link<T>, Option<T>) {
+        let Some(mut node) = link else {
+            return (None, None);
+        };
+
+        let removed = match value.cmp(&node.value) {
+            Ordering::Less => {
+                let (left, removed) = Self::remove_at(node.left.take(), value);
+                node.left = left;
+                removed
+            }
+            Ordering::Greater => {
+                let (right, removed) = Self::remove_at(node.right.take(), value);
+                node.right = right;
+                removed
+            }
+            Ordering::Equal => {
+                let AvlNode { value, left, right, .. } = *node;
+                let replacement = match (left, right) {
+                    (None, child) | (child, None) => child,
+                    (Some(left), Some(right)) => {
+                        // Replace the node with its in-order successor
+                        let (right, mut successor) = Self::remove_min(right);
+                        successor.left = Some(left);
+                        successor.right = right;
+                        Some(rebalance(successor))
+                    }
+                };
+                return (replacement, Some(value));
+            }
+        };
+        (Some(rebalance(node)), removed)
+    }
+
+    // Private method to detach the minimum node of a subtree, returning the
+    // rebalanced remainder and the detached node
+    fn remove_min(mut node: Box<AvlNode<T>>) -> (Link<T>, Box<AvlNode<T>>) {
+        match node.left.take() {
+            None => (node.right.take(), node),
+            Some(left) => {
+                let (left, min) = Self::remove_min(left);
+                node.left = left;
+                (Some(rebalance(node)), min)
+            }
+        }
+    }
+}
+
+/// An iterator over the elements of an [`AVLTree`] in ascending order
+///
+/// This is created by [`AVLTree::iter`].
+pub struct Iter<'a, T> {
+    // Nodes whose value and right subtree are still to be visited
+    stack: Vec<&'a AvlNode<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    // Pushes a node and its chain of left descendants
+    fn push_left(&mut self, mut link: &'a Link<T>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        self.remaining -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+// An iterator over the elements of a tree within a range
+//
+// This works like `Iter`, except that nodes below the start bound are never
+// pushed and iteration stops at the first node past the end bound.
+struct Range<'a, T, R> {
+    stack: Vec<&'a AvlNode<T>>,
+    range: R,
+}
+
+impl<'a, T: Ord, R: RangeBounds<T>> Range<'a, T, R> {
+    // Pushes the chain of left descendants that are not below the start
+    // bound, skipping over the left subtree of any node that is
+    fn push_left(&mut self, mut link: &'a Link<T>) {
+        while let Some(node) = link {
+            let after_start = match self.range.start_bound() {
+                Bound::Included(start) => node.value >= *start,
+                Bound::Excluded(start) => node.value > *start,
+                Bound::Unbounded => true,
+            };
+            if after_start {
+                self.stack.push(node);
+                link = &node.left;
+            } else {
+                link = &node.right;
+            }
+        }
+    }
+}
+
+impl<'a, T: Ord, R: RangeBounds<T>> Iterator for Range<'a, T, R> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        let before_end = match self.range.end_bound() {
+            Bound::Included(end) => node.value <= *end,
+            Bound::Excluded(end) => node.value < *end,
+            Bound::Unbounded => true,
+        };
+        if !before_end {
+            self.stack.clear();
+            return None;
+        }
+
+        self.push_left(&node.right);
+        Some(&node.value)
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a AVLTree<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Ord> Default for AVLTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for AVLTree<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = AVLTree::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<T: Ord> Extend<T> for AVLTree<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: Ord + fmt::Debug> fmt::Debug for AVLTree<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Checks ordering, stored heights and balance of every node, returning
+    // the subtree height
+    fn check_invariants<T: Ord>(link: &Link<T>) -> usize {
+        let Some(node) = link else {
+            return 0;
+        };
+        if let Some(left) = &node.left {
+            assert!(left.value < node.value);
+        }
+        if let Some(right) = &node.right {
+            assert!(right.value > node.value);
+        }
+
+        let left = check_invariants(&node.left);
+        let right = check_invariants(&node.right);
+        assert!(left.abs_diff(right) <= 1, "node is out of balance");
+        assert_eq!(node.height, 1 + left.max(right));
+        node.height
+    }
+
+    // The maximum height of an AVL tree with `n` nodes
+    fn avl_bound(n: usize) -> f64 {
+        1.4405 * ((n + 2) as f64).log2() - 0.3277
+    }
+
+    #[test]
+    fn test_insert_and_contains() {
+        let mut tree = AVLTree::new();
+        assert!(tree.is_empty());
+        assert!(!tree.contains(&1));
+
+        assert!(tree.insert(5));
+        assert!(tree.insert(3));
+        assert!(tree.insert(8));
+        assert!(!tree.insert(5));
+        assert_eq!(tree.len(), 3);
+
+        assert!(tree.contains(&3));
+        assert!(tree.contains(&5));
+        assert!(tree.contains(&8));
+        assert!(!tree.contains(&4));
+    }
+
+    #[test]
+    fn test_sorted_inserts_stay_balanced() {
+        let mut tree = AVLTree::new();
+        for i in 0..1000 {
+            tree.insert(i);
+        }
+
+        assert_eq!(tree.len(), 1000);
+        check_invariants(&tree.root);
+        assert!((tree.height() as f64) <= avl_bound(1000), "height {} too large", tree.height());
+
+        let values: Vec<i32> = tree.iter().copied().collect();
+        assert_eq!(values, (0..1000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut tree: AVLTree<i32> = (0..1000).rev().collect();
+
+        for i in (0..1000).step_by(3) {
+            assert!(tree.remove(&i));
+            check_invariants(&tree.root);
+        }
+        assert!(!tree.remove(&0));
+        assert_eq!(tree.len(), 666);
+        assert!((tree.height() as f64) <= avl_bound(666));
+
+        for i in 0..1000 {
+            assert_eq!(tree.contains(&i), i % 3 != 0);
+        }
+        assert!(tree.iter().zip(tree.iter().skip(1)).all(|(a, b)| a < b));
+    }
+
+    #[test]
+    fn test_remove_all() {
+        let mut tree: AVLTree<i32> = [4, 2, 6, 1, 3, 5, 7].into_iter().collect();
+        for i in [4, 1, 7, 2, 6, 3, 5] {
+            assert!(tree.remove(&i));
+            check_invariants(&tree.root);
+        }
+        assert!(tree.is_empty());
+        assert_eq!(tree.height(), 0);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let mut tree = AVLTree::new();
+        assert_eq!(tree.min(), None);
+        assert_eq!(tree.max(), None);
+
+        tree.extend([5, 2, 9, 1, 7]);
+        assert_eq!(tree.min(), Some(&1));
+        assert_eq!(tree.max(), Some(&9));
+    }
+
+    #[test]
+    fn test_range() {
+        let tree: AVLTree<i32> = (0..10).collect();
+
+        let values: Vec<i32> = tree.range(3..=7).copied().collect();
+        assert_eq!(values, [3, 4, 5, 6, 7]);
+
+        let values: Vec<i32> = tree.range(3..7).copied().collect();
+        assert_eq!(values, [3, 4, 5, 6]);
+
+        let values: Vec<i32> = tree.range(..2).copied().collect();
+        assert_eq!(values, [0, 1]);
+
+        let values: Vec<i32> = tree.range((Bound::Excluded(7), Bound::Unbounded)).copied().collect();
+        assert_eq!(values, [8, 9]);
+
+        assert_eq!(tree.range(..).count(), 10);
+        assert_eq!(tree.range(20..).count(), 0);
+        assert_eq!(tree.range(5..5).count(), 0);
+    }
+
+    #[test]
+    fn test_range_prunes_subtrees() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static COMPARISONS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        // An integer that counts how often it is compared
+        #[derive(PartialEq, Eq)]
+        struct Counted(i32);
+
+        impl PartialOrd for Counted {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Counted {
+            fn cmp(&self, other: &Self) -> Ordering {
+                COMPARISONS.with(|c| c.set(c.get() + 1));
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let tree: AVLTree<Counted> = (0..1000).map(Counted).collect();
+        COMPARISONS.with(|c| c.set(0));
+
+        let values: Vec<i32> = tree.range(Counted(3)..=Counted(7)).map(|c| c.0).collect();
+        assert_eq!(values, [3, 4, 5, 6, 7]);
+
+        // Each visited node is compared against one bound, so a full
+        // traversal would need at least 1000 comparisons
+        let comparisons = COMPARISONS.with(|c| c.get());
+        assert!(comparisons <= 4 * tree.height() + 10, "{} comparisons", comparisons);
+    }
+
+    #[test]
+    fn test_iter_len() {
+        let tree: AVLTree<i32> = [3, 1, 2].into_iter().collect();
+        let mut iter = tree.iter();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(format!("{:?}", tree), "{1, 2, 3}");
+    }
+}
@@ -0,0 +1,413 @@
+//! A binary heap implementation
+//!
+//! This module provides a priority queue implemented as a binary max-heap
+//! stored in the crate's own [`Vector`]. The ordering can be customized with
+//! a comparator, which also makes min-heaps and heaps over types without a
+//! natural `Ord` possible.
+
+use core::cmp::Ordering;
+use core::fmt;
+
+use crate::vector::Vector;
+
+/// A priority queue implemented as a binary max-heap
+///
+/// The greatest element is always at the top of the heap, so `peek` is O(1)
+/// while `push` and `pop` are O(log n).
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::binary_heap::BinaryHeap;
+///
+/// let mut heap = BinaryHeap::new();
+/// heap.push(3);
+/// heap.push(5);
+/// heap.push(1);
+///
+/// assert_eq!(heap.peek(), Some(&5));
+/// assert_eq!(heap.pop(), Some(5));
+/// assert_eq!(heap.pop(), Some(3));
+/// assert_eq!(heap.pop(), Some(1));
+/// assert_eq!(heap.pop(), None);
+/// ```
+///
+/// The heap keeps the element that compares greatest under its comparator
+/// `C` at the top. By default this is the natural `Ord` order; see
+/// [`new_min`] and [`new_by`] for other orderings.
+///
+/// [`new_min`]: BinaryHeap::new_min
+/// [`new_by`]: BinaryHeap::new_by
+pub struct BinaryHeap<T, C = MaxComparator> {
+    data: Vector<T>,
+    cmp: C,
+}
+
+/// An ordering used by a [`BinaryHeap`] to decide which element is on top
+///
+/// The element that compares greatest is popped first. This is implemented
+/// for any `FnMut(&T, &T) -> Ordering` closure.
+pub trait Compare<T> {
+    /// Compares two elements
+    fn compare(&mut self, a: &T, b: &T) -> Ordering;
+}
+
+impl<T, F: FnMut(&T, &T) -> Ordering> Compare<T> for F {
+    fn compare(&mut self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
+
+/// The natural `Ord` ordering, which makes a max-heap
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MaxComparator;
+
+impl<T: Ord> Compare<T> for MaxComparator {
+    fn compare(&mut self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// The reverse of the natural `Ord` ordering, which makes a min-heap
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MinComparator;
+
+impl<T: Ord> Compare<T> for MinComparator {
+    fn compare(&mut self, a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+}
+
+impl<T: Ord> Default for BinaryHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    /// Creates a new, empty max-heap
+    pub fn new() -> Self {
+        Self::new_by(MaxComparator)
+    }
+
+    /// Creates a new max-heap with the specified capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_by(capacity, MaxComparator)
+    }
+
+    /// Builds a max-heap from a vector in O(n)
+    ///
+    /// Rather than pushing each element, this sifts down every internal node
+    /// starting from the last one, which is linear in the number of elements.
+    pub fn from_vec(vec: Vector<T>) -> Self {
+        Self::from_vec_by(vec, MaxComparator)
+    }
+}
+
+impl<T: Ord> BinaryHeap<T, MinComparator> {
+    /// Creates a new, empty min-heap
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::binary_heap::BinaryHeap;
+    ///
+    /// let mut heap = BinaryHeap::new_min();
+    /// heap.push(3);
+    /// heap.push(1);
+    /// heap.push(2);
+    ///
+    /// assert_eq!(heap.pop(), Some(1));
+    /// assert_eq!(heap.pop(), Some(2));
+    /// ```
+    pub fn new_min() -> Self {
+        Self::new_by(MinComparator)
+    }
+}
+
+impl<T, C: Compare<T>> BinaryHeap<T, C> {
+    /// Creates a new, empty heap ordered by `cmp`
+    ///
+    /// The element that compares greatest under `cmp` is popped first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::binary_heap::BinaryHeap;
+    ///
+    /// let mut heap = BinaryHeap::new_by(|a: &(u32, &str), b: &(u32, &str)| b.0.cmp(&a.0));
+    /// heap.push((2, "second"));
+    /// heap.push((1, "first"));
+    ///
+    /// assert_eq!(heap.pop(), Some((1, "first")));
+    /// ```
+    pub fn new_by(cmp: C) -> Self {
+        BinaryHeap {
+            data: Vector::new(),
+            cmp,
+        }
+    }
+
+    /// Creates a new heap ordered by `cmp` with the specified capacity
+    pub fn with_capacity_by(capacity: usize, cmp: C) -> Self {
+        BinaryHeap {
+            data: Vector::with_capacity(capacity),
+            cmp,
+        }
+    }
+
+    /// Builds a heap ordered by `cmp` from a vector in O(n)
+    pub fn from_vec_by(vec: Vector<T>, cmp: C) -> Self {
+        let mut heap = BinaryHeap { data: vec, cmp };
+        let len = heap.data.len();
+        for index in (0..len / 2).rev() {
+            heap.sift_down_range(index, len);
+        }
+        heap
+    }
+
+    /// Consumes the heap and returns its elements sorted in ascending order
+    /// according to the heap's comparator
+    ///
+    /// The greatest element is repeatedly popped into the slot freed at the
+    /// end of the buffer, so no extra allocation is made.
+    pub fn into_sorted_vec(mut self) -> Vector<T> {
+        let mut end = self.data.len();
+        while end > 1 {
+            end -= 1;
+            self.data.swap(0, end);
+            self.sift_down_range(0, end);
+        }
+        self.data
+    }
+
+    /// Returns the number of elements in the heap
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns whether the heap is empty
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns a reference to the greatest element, or `None` if the heap is empty
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Pushes an element onto the heap
+    pub fn push(&mut self, element: T) {
+        self.data.push(element);
+        self.sift_up(self.data.len() - 1);
+    }
+
+    /// Removes the greatest element from the heap and returns it
+    pub fn pop(&mut self) -> Option<T> {
+        let last = self.data.len().checked_sub(1)?;
+        self.data.swap(0, last);
+        let top = self.data.pop();
+
+        if !self.data.is_empty() {
+            self.sift_down_range(0, self.data.len());
+        }
+
+        top
+    }
+
+    // Private method to move the element at `index` up until its parent is
+    // no smaller than it
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.cmp.compare(&self.data[index], &self.data[parent]) != Ordering::Greater {
+                break;
+            }
+
+            self.data.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    // Private method to move the element at `index` down until both of its
+    // children are no greater than it, considering only the first `len`
+    // elements as part of the heap
+    fn sift_down_range(&mut self, mut index: usize, len: usize) {
+        loop {
+            let left = 2 * index + 1;
+            let right = left + 1;
+            let mut largest = index;
+
+            if left < len && self.cmp.compare(&self.data[left], &self.data[largest]) == Ordering::Greater {
+                largest = left;
+            }
+            if right < len && self.cmp.compare(&self.data[right], &self.data[largest]) == Ordering::Greater {
+                largest = right;
+            }
+            if largest == index {
+                break;
+            }
+
+            self.data.swap(index, largest);
+            index = largest;
+        }
+    }
+}
+
+impl<T: Clone, C: Clone> Clone for BinaryHeap<T, C> {
+    fn clone(&self) -> Self {
+        BinaryHeap {
+            data: self.data.clone(),
+            cmp: self.cmp.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, C> fmt::Debug for BinaryHeap<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.data.iter()).finish()
+    }
+}
+
+impl<T: Ord> From<Vector<T>> for BinaryHeap<T> {
+    fn from(vec: Vector<T>) -> Self {
+        Self::from_vec(vec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::seq::SliceRandom;
+
+    #[test]
+    fn test_push_pop_shuffled() {
+        let mut values: Vec<i32> = (0..100).collect();
+        values.shuffle(&mut rand::thread_rng());
+
+        let mut heap = BinaryHeap::with_capacity(values.len());
+        for value in values {
+            heap.push(value);
+        }
+        assert_eq!(heap.len(), 100);
+
+        for expected in (0..100).rev() {
+            assert_eq!(heap.pop(), Some(expected));
+        }
+        assert_eq!(heap.pop(), None);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut heap = BinaryHeap::new();
+        assert_eq!(heap.peek(), None);
+
+        heap.push(2);
+        assert_eq!(heap.peek(), Some(&2));
+        heap.push(7);
+        assert_eq!(heap.peek(), Some(&7));
+        heap.push(4);
+        assert_eq!(heap.peek(), Some(&7));
+
+        assert_eq!(heap.pop(), Some(7));
+        assert_eq!(heap.peek(), Some(&4));
+        assert_eq!(heap.len(), 2);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let mut heap = BinaryHeap::new();
+        for value in [3, 1, 3, 2, 1] {
+            heap.push(value);
+        }
+
+        let mut popped = Vec::new();
+        while let Some(value) = heap.pop() {
+            popped.push(value);
+        }
+        assert_eq!(popped, vec![3, 3, 2, 1, 1]);
+    }
+
+    #[test]
+    fn test_from_vec_matches_push() {
+        let mut values: Vec<i32> = (0..200).map(|i| i % 37).collect();
+        values.shuffle(&mut rand::thread_rng());
+
+        let mut pushed = BinaryHeap::new();
+        let mut vec = Vector::new();
+        for &value in &values {
+            pushed.push(value);
+            vec.push(value);
+        }
+        let mut heapified = BinaryHeap::from(vec);
+
+        assert_eq!(heapified.len(), pushed.len());
+        while let Some(expected) = pushed.pop() {
+            assert_eq!(heapified.pop(), Some(expected));
+        }
+        assert!(heapified.is_empty());
+    }
+
+    #[test]
+    fn test_into_sorted_vec() {
+        let mut values: Vec<i32> = (0..100).collect();
+        values.shuffle(&mut rand::thread_rng());
+
+        let mut vec = Vector::new();
+        for value in values {
+            vec.push(value);
+        }
+
+        let sorted = BinaryHeap::from_vec(vec).into_sorted_vec();
+        assert_eq!(sorted.len(), 100);
+        assert!(sorted.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        let empty: BinaryHeap<i32> = BinaryHeap::new();
+        assert!(empty.into_sorted_vec().is_empty());
+    }
+
+    #[test]
+    fn test_min_heap() {
+        let mut values: Vec<i32> = (0..50).collect();
+        values.shuffle(&mut rand::thread_rng());
+
+        let mut heap = BinaryHeap::new_min();
+        for value in values {
+            heap.push(value);
+        }
+
+        assert_eq!(heap.peek(), Some(&0));
+        for expected in 0..50 {
+            assert_eq!(heap.pop(), Some(expected));
+        }
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn test_custom_comparator() {
+        // A type without a natural ordering, prioritized by distance to zero
+        #[derive(Debug, PartialEq)]
+        struct Point(f64, f64);
+
+        let mut heap = BinaryHeap::new_by(|a: &Point, b: &Point| {
+            let da = a.0 * a.0 + a.1 * a.1;
+            let db = b.0 * b.0 + b.1 * b.1;
+            db.partial_cmp(&da).unwrap()
+        });
+        heap.push(Point(3.0, 4.0));
+        heap.push(Point(1.0, 0.0));
+        heap.push(Point(0.0, 2.0));
+
+        assert_eq!(heap.pop(), Some(Point(1.0, 0.0)));
+        assert_eq!(heap.pop(), Some(Point(0.0, 2.0)));
+        assert_eq!(heap.pop(), Some(Point(3.0, 4.0)));
+
+        let mut vec = Vector::new();
+        for value in [5, 1, 4, 2, 3] {
+            vec.push(value);
+        }
+        let sorted = BinaryHeap::from_vec_by(vec, MinComparator).into_sorted_vec();
+        assert_eq!(&*sorted, &[5, 4, 3, 2, 1]);
+    }
+}
@@ -0,0 +1,304 @@
+//! A dense bit set implementation
+//!
+//! This module provides a set of small non-negative integers stored as one
+//! bit each, packed into 64-bit words. Membership tests and updates are a
+//! single word operation, and set operations work a word at a time.
+
+use core::fmt;
+use core::iter::{FromIterator, FusedIterator};
+
+use crate::vector::Vector;
+
+// Number of bits stored in each word
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A set of `usize` values backed by a bit vector
+///
+/// The set grows automatically to hold the largest value inserted, so its
+/// memory use is proportional to that value rather than to the number of
+/// elements. It is a good fit for dense sets of small integers.
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::bitset::BitSet;
+///
+/// let mut set = BitSet::new();
+/// set.insert(3);
+/// set.insert(70);
+/// set.insert(3);
+///
+/// assert!(set.contains(70));
+/// assert!(!set.contains(4));
+/// assert_eq!(set.len(), 2);
+/// assert_eq!(set.iter().collect::<Vec<_>>(), [3, 70]);
+/// ```
+#[derive(Clone, Default)]
+pub struct BitSet {
+    words: Vector<u64>,
+}
+
+impl BitSet {
+    /// Creates a new, empty set
+    pub fn new() -> Self {
+        BitSet { words: Vector::new() }
+    }
+
+    /// Creates a new, empty set with room for values below `bits` without
+    /// reallocating
+    pub fn with_capacity(bits: usize) -> Self {
+        BitSet {
+            words: Vector::with_capacity(bits.div_ceil(WORD_BITS)),
+        }
+    }
+
+    /// Returns the number of values in the set
+    ///
+    /// This counts the set bits of every word, so it takes time proportional
+    /// to the largest value ever inserted.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns whether the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Removes every value from the set, keeping the allocated words
+    pub fn clear(&mut self) {
+        self.words.iter_mut().for_each(|word| *word = 0);
+    }
+
+    /// Adds `value` to the set, growing it if needed
+    ///
+    /// Returns whether the value was newly inserted.
+    pub fn insert(&mut self, value: usize) -> bool {
+        let (word, mask) = Self::locate(value);
+        if word >= self.words.len() {
+            self.words.reserve(word + 1 - self.words.len());
+            while self.words.len() <= word {
+                self.words.push(0);
+            }
+        }
+
+        let was_present = self.words[word] & mask != 0;
+        self.words[word] |= mask;
+        !was_present
+    }
+
+    /// Removes `value` from the set
+    ///
+    /// Returns whether the value was present.
+    pub fn remove(&mut self, value: usize) -> bool {
+        let (word, mask) = Self::locate(value);
+        match self.words.get_mut(word) {
+            Some(bits) if *bits & mask != 0 => {
+                *bits &= !mask;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns whether `value` is in the set
+    pub fn contains(&self, value: usize) -> bool {
+        let (word, mask) = Self::locate(value);
+        self.words.get(word).is_some_and(|bits| bits & mask != 0)
+    }
+
+    /// Returns a new set with the values in `self`, `other`, or both
+    pub fn union(&self, other: &BitSet) -> BitSet {
+        let (longer, shorter) = if self.words.len() >= other.words.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        let mut result = longer.clone();
+        for (word, bits) in result.words.iter_mut().zip(shorter.words.iter()) {
+            *word |= bits;
+        }
+        result
+    }
+
+    /// Returns a new set with the values in both `self` and `other`
+    pub fn intersection(&self, other: &BitSet) -> BitSet {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    /// Returns a new set with the values in `self` but not in `other`
+    pub fn difference(&self, other: &BitSet) -> BitSet {
+        let mut result = self.clone();
+        for (word, bits) in result.words.iter_mut().zip(other.words.iter()) {
+            *word &= !bits;
+        }
+        result
+    }
+
+    /// Returns an iterator over the values in ascending order
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            words: &self.words,
+            index: 0,
+            current: self.words.first().copied().unwrap_or(0),
+        }
+    }
+
+    // Private method to find the word holding `value` and its bit mask
+    fn locate(value: usize) -> (usize, u64) {
+        (value / WORD_BITS, 1 << (value % WORD_BITS))
+    }
+
+    // Private method to combine the words both sets have in common
+    fn zip_with<F: Fn(u64, u64) -> u64>(&self, other: &BitSet, f: F) -> BitSet {
+        let words = self
+            .words
+            .iter()
+            .zip(other.words.iter())
+            .map(|(&a, &b)| f(a, b))
+            .collect();
+        BitSet { words }
+    }
+}
+
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        // Trailing zero words do not hold any values, so sets that grew to
+        // different sizes can still be equal
+        let (longer, shorter) = if self.words.len() >= other.words.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let (common, rest) = longer.words.split_at(shorter.words.len());
+        common == &*shorter.words && rest.iter().all(|&word| word == 0)
+    }
+}
+
+impl Eq for BitSet {}
+
+impl fmt::Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = BitSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<usize> for BitSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a BitSet {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of a [`BitSet`] in ascending order
+pub struct Iter<'a> {
+    words: &'a [u64],
+    index: usize,
+    // The bits of `words[index]` that have not been yielded yet
+    current: u64,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            self.index += 1;
+            self.current = *self.words.get(self.index)?;
+        }
+
+        let bit = self.current.trailing_zeros() as usize;
+        // Clear the lowest set bit
+        self.current &= self.current - 1;
+        Some(self.index * WORD_BITS + bit)
+    }
+}
+
+impl FusedIterator for Iter<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_remove_contains() {
+        let mut set = BitSet::new();
+        assert!(set.is_empty());
+        assert!(!set.contains(0));
+
+        assert!(set.insert(0));
+        assert!(set.insert(63));
+        assert!(set.insert(64));
+        assert!(set.insert(1000));
+        assert!(!set.insert(64));
+        assert_eq!(set.len(), 4);
+
+        assert!(set.contains(63));
+        assert!(set.contains(1000));
+        assert!(!set.contains(999));
+        assert!(!set.contains(100_000));
+
+        assert!(set.remove(63));
+        assert!(!set.remove(63));
+        assert!(!set.remove(100_000));
+        assert_eq!(set.len(), 3);
+
+        set.clear();
+        assert!(set.is_empty());
+        assert!(!set.contains(1000));
+    }
+
+    #[test]
+    fn test_iter_ascending() {
+        let set: BitSet = [200, 5, 64, 0, 63, 129, 5].into_iter().collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 5, 63, 64, 129, 200]);
+        assert_eq!(format!("{:?}", set), "{0, 5, 63, 64, 129, 200}");
+
+        // Empty words, including at the start, are skipped
+        let set: BitSet = [300].into_iter().collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), [300]);
+        assert_eq!(BitSet::new().iter().next(), None);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: BitSet = [1, 2, 3, 100].into_iter().collect();
+        let b: BitSet = [2, 3, 4, 500].into_iter().collect();
+
+        assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), [1, 2, 3, 4, 100, 500]);
+        assert_eq!(b.union(&a), a.union(&b));
+        assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), [2, 3]);
+        assert_eq!(a.difference(&b).iter().collect::<Vec<_>>(), [1, 100]);
+        assert_eq!(b.difference(&a).iter().collect::<Vec<_>>(), [4, 500]);
+    }
+
+    #[test]
+    fn test_eq_ignores_capacity() {
+        let mut a: BitSet = [1, 2].into_iter().collect();
+        let b: BitSet = [1, 2].into_iter().collect();
+        a.insert(1000);
+        assert_ne!(a, b);
+        a.remove(1000);
+        assert_eq!(a, b);
+        assert_eq!(BitSet::with_capacity(500), BitSet::new());
+    }
+}
@@ -0,0 +1,287 @@
+//! A Bloom filter implementation
+//!
+//! This module provides a space-efficient probabilistic set. Membership
+//! queries may return false positives, but never false negatives.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use std::collections::hash_map::DefaultHasher;
+
+use crate::vector::Vector;
+
+// Number of bits stored in each word of the bit array
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A space-efficient probabilistic set
+///
+/// The filter is sized up front for an expected number of items and a target
+/// false-positive rate. Each item sets `k` bits chosen by double hashing, so
+/// only two hashes are computed per operation regardless of `k`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::bloom_filter::BloomFilter;
+///
+/// let mut filter = BloomFilter::with_rate(100, 0.01);
+/// filter.insert(&"apple");
+/// filter.insert(&"banana");
+///
+/// assert!(filter.contains(&"apple"));
+/// assert!(filter.contains(&"banana"));
+/// ```
+pub struct BloomFilter<T: Hash> {
+    bits: Vector<u64>,
+    num_bits: usize,
+    num_hashes: usize,
+    _marker: PhantomData<fn(&T)>,
+}
+
+impl<T: Hash> BloomFilter<T> {
+    /// Creates a filter sized for `expected_items` with the given target
+    /// false-positive rate
+    ///
+    /// The bit-array size `m` and hash count `k` are chosen optimally:
+    /// `m = -n ln(p) / ln(2)^2` and `k = (m / n) ln(2)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `false_positive_rate` is not strictly between 0 and 1.
+    pub fn with_rate(expected_items: usize, false_positive_rate: f64) -> Self {
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "false positive rate must be in (0, 1)"
+        );
+
+        let n = expected_items.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let num_bits = ((-n * false_positive_rate.ln()) / (ln2 * ln2)).ceil().max(1.0) as usize;
+        let num_hashes = ((num_bits as f64 / n) * ln2).round().max(1.0) as usize;
+
+        Self::with_params(num_bits, num_hashes)
+    }
+
+    /// Creates a filter with an explicit bit-array size and hash count
+    ///
+    /// # Panics
+    ///
+    /// Panics if either parameter is zero.
+    pub fn with_params(num_bits: usize, num_hashes: usize) -> Self {
+        assert!(num_bits > 0, "bloom filter needs at least one bit");
+        assert!(num_hashes > 0, "bloom filter needs at least one hash");
+
+        let words = num_bits.div_ceil(WORD_BITS);
+        let mut bits = Vector::with_capacity(words);
+        for _ in 0..words {
+            bits.push(0);
+        }
+
+        BloomFilter {
+            bits,
+            num_bits,
+            num_hashes,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the size of the bit array
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+
+    /// Returns the number of bits set per item
+    pub fn num_hashes(&self) -> usize {
+        self.num_hashes
+    }
+
+    /// Adds an item to the filter
+    pub fn insert(&mut self, item: &T) {
+        let (h1, h2) = Self::base_hashes(item);
+        for i in 0..self.num_hashes {
+            let bit = self.bit_index(h1, h2, i);
+            self.bits[bit / WORD_BITS] |= 1 << (bit % WORD_BITS);
+        }
+    }
+
+    /// Returns whether the item may have been added to the filter
+    ///
+    /// A `false` result is definitive; a `true` result may be a false
+    /// positive.
+    pub fn contains(&self, item: &T) -> bool {
+        let (h1, h2) = Self::base_hashes(item);
+        (0..self.num_hashes).all(|i| {
+            let bit = self.bit_index(h1, h2, i);
+            self.bits[bit / WORD_BITS] & (1 << (bit % WORD_BITS)) != 0
+        })
+    }
+
+    /// Merges another filter into this one by OR-ing the bit arrays
+    ///
+    /// Afterwards the filter reports every item that was added to either
+    /// filter. Both filters must have been created with the same bit-array
+    /// size and hash count; otherwise an error is returned and `self` is left
+    /// unchanged.
+    #[allow(clippy::result_unit_err)]
+    pub fn union(&mut self, other: &BloomFilter<T>) -> Result<(), ()> {
+        if self.num_bits != other.num_bits || self.num_hashes != other.num_hashes {
+            return Err(());
+        }
+
+        for (word, other_word) in self.bits.iter_mut().zip(other.bits.iter()) {
+            *word |= *other_word;
+        }
+        Ok(())
+    }
+
+    /// Estimates the number of distinct items added to the filter
+    ///
+    /// This uses the standard estimate `n = -(m / k) ln(1 - X / m)`, where
+    /// `X` is the number of set bits.
+    pub fn estimated_len(&self) -> usize {
+        let set_bits: usize = self.bits.iter().map(|word| word.count_ones() as usize).sum();
+        let m = self.num_bits as f64;
+        let k = self.num_hashes as f64;
+
+        // The estimate diverges once every bit is set, so cap it just below
+        let x = (set_bits as f64).min(m - 0.5);
+        (-(m / k) * (1.0 - x / m).ln()).round() as usize
+    }
+
+    /// Removes every item from the filter
+    pub fn clear(&mut self) {
+        for word in self.bits.iter_mut() {
+            *word = 0;
+        }
+    }
+
+    // Private method to compute the two base hashes used for double hashing
+    fn base_hashes(item: &T) -> (u64, u64) {
+        let mut hasher = DefaultHasher::new();
+        item.hash(&mut hasher);
+        let h1 = hasher.finish();
+
+        // Continue from the first state with a salt to derive a second,
+        // independent-looking hash
+        hasher.write_u64(0x9e37_79b9_7f4a_7c15);
+        // Forcing the step odd keeps it from being zero, which would map
+        // every i to the same bit
+        let h2 = hasher.finish() | 1;
+
+        (h1, h2)
+    }
+
+    // Private method to compute the i-th bit index as h1 + i * h2 (mod m)
+    fn bit_index(&self, h1: u64, h2: u64, i: usize) -> usize {
+        (h1.wrapping_add((i as u64).wrapping_mul(h2)) % self.num_bits as u64) as usize
+    }
+}
+
+impl<T: Hash> Clone for BloomFilter<T> {
+    fn clone(&self) -> Self {
+        BloomFilter {
+            bits: self.bits.clone(),
+            num_bits: self.num_bits,
+            num_hashes: self.num_hashes,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Hash> fmt::Debug for BloomFilter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BloomFilter")
+            .field("num_bits", &self.num_bits)
+            .field("num_hashes", &self.num_hashes)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_optimal_params() {
+        let filter: BloomFilter<u32> = BloomFilter::with_rate(1000, 0.01);
+        // m = -1000 ln(0.01) / ln(2)^2 ~= 9586, k ~= 7
+        assert_eq!(filter.num_bits(), 9586);
+        assert_eq!(filter.num_hashes(), 7);
+    }
+
+    #[test]
+    fn test_no_false_negatives() {
+        let mut filter = BloomFilter::with_rate(10_000, 0.01);
+        for i in 0..10_000u32 {
+            filter.insert(&i);
+        }
+        for i in 0..10_000u32 {
+            assert!(filter.contains(&i));
+        }
+    }
+
+    #[test]
+    fn test_false_positive_rate() {
+        let target = 0.01;
+        let mut filter = BloomFilter::with_rate(10_000, target);
+        for i in 0..10_000u32 {
+            filter.insert(&i);
+        }
+
+        let trials = 100_000u32;
+        let false_positives = (10_000..10_000 + trials)
+            .filter(|i| filter.contains(i))
+            .count();
+        let rate = false_positives as f64 / trials as f64;
+        assert!(rate < target * 1.5, "false positive rate {} too high", rate);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut filter = BloomFilter::with_rate(10, 0.1);
+        filter.insert(&"a");
+        assert!(filter.contains(&"a"));
+
+        filter.clear();
+        assert!(!filter.contains(&"a"));
+    }
+
+    #[test]
+    fn test_union() {
+        let mut evens = BloomFilter::with_rate(1000, 0.01);
+        let mut odds = BloomFilter::with_rate(1000, 0.01);
+        for i in 0..1000u32 {
+            if i % 2 == 0 {
+                evens.insert(&i);
+            } else {
+                odds.insert(&i);
+            }
+        }
+
+        assert_eq!(evens.union(&odds), Ok(()));
+        for i in 0..1000u32 {
+            assert!(evens.contains(&i));
+        }
+
+        let mismatched = BloomFilter::with_rate(10, 0.01);
+        assert_eq!(evens.union(&mismatched), Err(()));
+    }
+
+    #[test]
+    fn test_estimated_len() {
+        let mut filter = BloomFilter::with_rate(10_000, 0.01);
+        assert_eq!(filter.estimated_len(), 0);
+
+        for i in 0..5_000u32 {
+            filter.insert(&i);
+        }
+        let estimate = filter.estimated_len() as f64;
+        assert!((estimate - 5_000.0).abs() < 5_000.0 * 0.05, "estimate {} too far off", estimate);
+
+        // Re-inserting the same items does not change the estimate
+        for i in 0..5_000u32 {
+            filter.insert(&i);
+        }
+        assert_eq!(filter.estimated_len() as f64, estimate);
+    }
+}
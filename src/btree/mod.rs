@@ -0,0 +1,603 @@
+//! A B-tree implementation
+//!
+//! This module provides an ordered map stored as a B-tree. Each node holds
+//! many sorted keys, which keeps the tree shallow and its memory accesses
+//! local. Full nodes are split on the way down during insertion, and nodes
+//! that are about to run short are refilled on the way down during removal,
+//! so neither operation ever has to walk back up the tree.
+
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::mem;
+
+// Minimum degree used when none is given
+const DEFAULT_MIN_DEGREE: usize = 6;
+
+// A node in the tree
+//
+// Internal nodes have exactly one more child than keys; leaves have none.
+struct BTreeNode<K, V> {
+    keys: Vec<K>,
+    vals: Vec<V>,
+    children: Vec<BTreeNode<K, V>>,
+}
+
+impl<K, V> BTreeNode<K, V> {
+    fn new() -> Self {
+        BTreeNode {
+            keys: Vec::new(),
+            vals: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+/// An ordered map stored as a B-tree
+///
+/// The tree is parameterized by its minimum degree `t`: every node other
+/// than the root holds between `t - 1` and `2t - 1` keys, and all leaves are
+/// at the same depth. Lookups, insertions and removals are O(t log_t n).
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::btree::BTreeMap;
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(3, "c");
+/// map.insert(1, "a");
+/// map.insert(2, "b");
+///
+/// assert_eq!(map.get(&2), Some(&"b"));
+/// assert_eq!(map.keys().collect::<Vec<_>>(), [&1, &2, &3]);
+///
+/// assert_eq!(map.remove(&2), Some("b"));
+/// assert_eq!(map.get(&2), None);
+/// ```
+pub struct BTreeMap<K: Ord, V> {
+    root: BTreeNode<K, V>,
+    len: usize,
+    min_degree: usize,
+}
+
+impl<K: Ord, V> BTreeMap<K, V> {
+    /// Creates a new, empty map with the default minimum degree
+    pub fn new() -> Self {
+        Self::with_min_degree(DEFAULT_MIN_DEGREE)
+    }
+
+    /// Creates a new, empty map with the given minimum degree
+    ///
+    /// Each node holds at most `2 * min_degree - 1` keys. Larger degrees give
+    /// shallower trees at the cost of more work per node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_degree` is less than 2.
+    pub fn with_min_degree(min_degree: usize) -> Self {
+        assert!(min_degree >= 2, "B-tree minimum degree must be at least 2");
+
+        BTreeMap {
+            root: BTreeNode::new(),
+            len: 0,
+            min_degree,
+        }
+    }
+
+    /// Returns the minimum degree of the tree
+    pub fn min_degree(&self) -> usize {
+        self.min_degree
+    }
+
+    /// Returns the number of entries in the map
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the map is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts a key-value pair into the map
+    ///
+    /// If the key was already present, its value is replaced and the old
+    /// value is returned. The key itself is not updated.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let t = self.min_degree;
+        if self.root.keys.len() == 2 * t - 1 {
+            // Grow the tree by one level, splitting the old root in two
+            let old_root = mem::replace(&mut self.root, BTreeNode::new());
+            self.root.children.push(old_root);
+            Self::split_child(&mut self.root, 0, t);
+        }
+
+        let old = Self::insert_non_full(&mut self.root, key, value, t);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Returns a reference to the value for `key`
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut node = &self.root;
+        loop {
+            match node.keys.binary_search_by(|k| k.borrow().cmp(key)) {
+                Ok(i) => return Some(&node.vals[i]),
+                Err(i) => node = node.children.get(i)?,
+            }
+        }
+    }
+
+    /// Returns a mutable reference to the value for `key`
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut node = &mut self.root;
+        loop {
+            match node.keys.binary_search_by(|k| k.borrow().cmp(key)) {
+                Ok(i) => return Some(&mut node.vals[i]),
+                Err(i) => node = node.children.get_mut(i)?,
+            }
+        }
+    }
+
+    /// Returns whether the map contains `key`
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+
+    /// Removes `key` from the map, returning its value if it was present
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let removed = Self::remove_from(&mut self.root, key, self.min_degree);
+
+        // Shrink the tree by one level once the root runs out of keys. The
+        // descent merges children even when the key turns out to be missing,
+        // so this has to run before bailing out on `None`.
+        if self.root.keys.is_empty() && !self.root.is_leaf() {
+            self.root = self.root.children.pop().unwrap();
+        }
+
+        let (_, value) = removed?;
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Removes every entry from the map
+    pub fn clear(&mut self) {
+        self.root = BTreeNode::new();
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the entries in ascending key order
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            remaining: self.len,
+        };
+        iter.push_left(&self.root);
+        iter
+    }
+
+    /// Returns an iterator over the keys in ascending order
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    /// Returns an iterator over the values in ascending key order
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    // Private method to split the full child at `index` around its median,
+    // which moves up into `parent`
+    fn split_child(parent: &mut BTreeNode<K, V>, index: usize, t: usize) {
+        let child = &mut parent.children[index];
+
+        let mut right = BTreeNode::new();
+        right.keys = child.keys.split_off(t);
+        right.vals = child.vals.split_off(t);
+        if !child.is_leaf() {
+            right.children = child.children.split_off(t);
+        }
+        let median_key = child.keys.pop().unwrap();
+        let median_val = child.vals.pop().unwrap();
+
+        parent.keys.insert(index, median_key);
+        parent.vals.insert(index, median_val);
+        parent.children.insert(index + 1, right);
+    }
+
+    // Private method to insert into a subtree whose root is not full,
+    // splitting full children before descending into them
+    fn insert_non_full(node: &mut BTreeNode<K, V>, key: K, value: V, t: usize) -> Option<V> {
+        let mut index = match node.keys.binary_search(&key) {
+            Ok(i) => return Some(mem::replace(&mut node.vals[i], value)),
+            Err(i) => i,
+        };
+
+        if node.is_leaf() {
+            node.keys.insert(index, key);
+            node.vals.insert(index, value);
+            return None;
+        }
+
+        if node.children[index].keys.len() == 2 * t - 1 {
+            Self::split_child(node, index, t);
+            match key.cmp(&node.keys[index]) {
+                Ordering::Less => {}
+                Ordering::Equal => {
+                    return Some(mem::replace(&mut node.vals[index], value));
+                }
+                Ordering::Greater => index += 1,
+            }
+        }
+        Self::insert_non_full(&mut node.children[index], key, value, t)
+    }
+
+    // Private method to remove `key` from a subtree whose root has at least
+    // `t` keys (or is the tree's root), refilling children before
+    // descending into them
+    fn remove_from<Q>(node: &mut BTreeNode<K, V>, key: &Q, t: usize) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        match node.keys.binary_search_by(|k| k.borrow().cmp(key)) {
+            Ok(i) if node.is_leaf() => Some((node.keys.remove(i), node.vals.remove(i))),
+            Ok(i) => {
+                if node.children[i].keys.len() >= t {
+                    // Replace the key with its predecessor
+                    let (k, v) = Self::remove_max(&mut node.children[i], t);
+                    let k = mem::replace(&mut node.keys[i], k);
+                    let v = mem::replace(&mut node.vals[i], v);
+                    Some((k, v))
+                } else if node.children[i + 1].keys.len() >= t {
+                    // Replace the key with its successor
+                    let (k, v) = Self::remove_min(&mut node.children[i + 1], t);
+                    let k = mem::replace(&mut node.keys[i], k);
+                    let v = mem::replace(&mut node.vals[i], v);
+                    Some((k, v))
+                } else {
+                    // Both neighbours are minimal, so push the key down into
+                    // their merged node and remove it from there
+                    Self::merge_children(node, i);
+                    Self::remove_from(&mut node.children[i], key, t)
+                }
+            }
+            Err(_) if node.is_leaf() => None,
+            Err(i) => {
+                let i = Self::fill_child(node, i, t);
+                Self::remove_from(&mut node.children[i], key, t)
+            }
+        }
+    }
+
+    // Private method to remove the greatest entry of a subtree
+    fn remove_max(node: &mut BTreeNode<K, V>, t: usize) -> (K, V) {
+        if node.is_leaf() {
+            return (node.keys.pop().unwrap(), node.vals.pop().unwrap());
+        }
+        let i = Self::fill_child(node, node.children.len() - 1, t);
+        Self::remove_max(&mut node.children[i], t)
+    }
+
+    // Private method to remove the least entry of a subtree
+    fn remove_min(node: &mut BTreeNode<K, V>, t: usize) -> (K, V) {
+        if node.is_leaf() {
+            return (node.keys.remove(0), node.vals.remove(0));
+        }
+        let i = Self::fill_child(node, 0, t);
+        Self::remove_min(&mut node.children[i], t)
+    }
+
+    // Private method to make sure the child at `index` has at least `t` keys,
+    // borrowing from a sibling or merging with one
+    //
+    // Returns the index of the child that now covers the original one's key
+    // range, which moves left by one when merging with the left sibling.
+    fn fill_child(node: &mut BTreeNode<K, V>, index: usize, t: usize) -> usize {
+        if node.children[index].keys.len() >= t {
+            return index;
+        }
+
+        if index > 0 && node.children[index - 1].keys.len() >= t {
+            // Rotate an entry from the left sibling through the parent
+            let (left, right) = node.children.split_at_mut(index);
+            let (left, child) = (&mut left[index - 1], &mut right[0]);
+
+            let k = mem::replace(&mut node.keys[index - 1], left.keys.pop().unwrap());
+            let v = mem::replace(&mut node.vals[index - 1], left.vals.pop().unwrap());
+            child.keys.insert(0, k);
+            child.vals.insert(0, v);
+            if let Some(grandchild) = left.children.pop() {
+                child.children.insert(0, grandchild);
+            }
+            index
+        } else if index + 1 < node.children.len() && node.children[index + 1].keys.len() >= t {
+            // Rotate an entry from the right sibling through the parent
+            let (left, right) = node.children.split_at_mut(index + 1);
+            let (child, right) = (&mut left[index], &mut right[0]);
+
+            let k = mem::replace(&mut node.keys[index], right.keys.remove(0));
+            let v = mem::replace(&mut node.vals[index], right.vals.remove(0));
+            child.keys.push(k);
+            child.vals.push(v);
+            if !right.is_leaf() {
+                child.children.push(right.children.remove(0));
+            }
+            index
+        } else if index + 1 < node.children.len() {
+            Self::merge_children(node, index);
+            index
+        } else {
+            Self::merge_children(node, index - 1);
+            index - 1
+        }
+    }
+
+    // Private method to merge the child at `index + 1` and the separating
+    // key into the child at `index`
+    fn merge_children(node: &mut BTreeNode<K, V>, index: usize) {
+        let right = node.children.remove(index + 1);
+        let k = node.keys.remove(index);
+        let v = node.vals.remove(index);
+
+        let BTreeNode { keys, vals, children } = right;
+        let left = &mut node.children[index];
+        left.keys.push(k);
+        left.vals.push(v);
+        left.keys.extend(keys);
+        left.vals.extend(vals);
+        left.children.extend(children);
+    }
+}
+
+/// An iterator over the entries of a [`BTreeMap`] in ascending key order
+///
+/// This is created by [`BTreeMap::iter`].
+pub struct Iter<'a, K, V> {
+    // Nodes along the current path, with the index of the next key to yield
+    stack: Vec<(&'a BTreeNode<K, V>, usize)>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    // Pushes a node and its chain of leftmost descendants
+    fn push_left(&mut self, mut node: &'a BTreeNode<K, V>) {
+        loop {
+            self.stack.push((node, 0));
+            match node.children.first() {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, index)) = self.stack.last_mut() {
+            let node: &'a BTreeNode<K, V> = node;
+            if *index < node.keys.len() {
+                let i = *index;
+                *index += 1;
+                if !node.is_leaf() {
+                    self.push_left(&node.children[i + 1]);
+                }
+                self.remaining -= 1;
+                return Some((&node.keys[i], &node.vals[i]));
+            }
+            self.stack.pop();
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<'a, K: Ord, V> IntoIterator for &'a BTreeMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Ord, V> Default for BTreeMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for BTreeMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = BTreeMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for BTreeMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<K: Ord + fmt::Debug, V: fmt::Debug> fmt::Debug for BTreeMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::seq::SliceRandom;
+
+    // Checks key counts and leaf depths of every node below `node`,
+    // returning the depth of its leaves
+    fn check_invariants<K: Ord, V>(node: &BTreeNode<K, V>, t: usize, is_root: bool) -> usize {
+        assert!(node.keys.len() < 2 * t);
+        if !is_root {
+            assert!(node.keys.len() >= t - 1, "node has too few keys");
+        }
+        assert_eq!(node.keys.len(), node.vals.len());
+        assert!(node.keys.windows(2).all(|w| w[0] < w[1]));
+
+        if node.is_leaf() {
+            return 0;
+        }
+        assert_eq!(node.children.len(), node.keys.len() + 1);
+        let depths: Vec<usize> = node
+            .children
+            .iter()
+            .map(|child| check_invariants(child, t, false))
+            .collect();
+        assert!(depths.iter().all(|&d| d == depths[0]), "leaves at different depths");
+        depths[0] + 1
+    }
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut map = BTreeMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.get(&1), None);
+
+        assert_eq!(map.insert(1, "one"), None);
+        assert_eq!(map.insert(2, "two"), None);
+        assert_eq!(map.insert(1, "uno"), Some("one"));
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.get(&1), Some(&"uno"));
+        assert!(map.contains_key(&2));
+        assert!(!map.contains_key(&3));
+
+        if let Some(v) = map.get_mut(&2) {
+            *v = "dos";
+        }
+        assert_eq!(map.get(&2), Some(&"dos"));
+    }
+
+    #[test]
+    fn test_many_splits() {
+        for t in [2, 3, 6] {
+            let mut keys: Vec<u32> = (0..2000).collect();
+            keys.shuffle(&mut rand::thread_rng());
+
+            let mut map = BTreeMap::with_min_degree(t);
+            for &k in &keys {
+                assert_eq!(map.insert(k, k * 10), None);
+            }
+            assert_eq!(map.len(), 2000);
+            check_invariants(&map.root, t, true);
+
+            for k in 0..2000 {
+                assert_eq!(map.get(&k), Some(&(k * 10)));
+            }
+            let sorted: Vec<u32> = map.keys().copied().collect();
+            assert_eq!(sorted, (0..2000).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_remove_missing_key_collapses_root() {
+        let mut map = BTreeMap::with_min_degree(2);
+        for k in 1..=4 {
+            map.insert(k, k);
+        }
+        map.remove(&4);
+        // The root now holds one key over two minimal children
+        assert_eq!(map.root.keys.len(), 1);
+        assert_eq!(map.root.children.len(), 2);
+
+        // Looking for a missing key merges them on the way down
+        assert_eq!(map.remove(&5), None);
+        check_invariants(&map.root, 2, true);
+        assert!(map.root.is_leaf());
+        assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn test_many_merges() {
+        for t in [2, 3, 6] {
+            let mut keys: Vec<u32> = (0..2000).collect();
+            keys.shuffle(&mut rand::thread_rng());
+            let mut map: BTreeMap<u32, u32> = BTreeMap::with_min_degree(t);
+            map.extend(keys.iter().map(|&k| (k, k)));
+
+            // Remove every odd key in random order, checking the shape as
+            // nodes borrow and merge
+            keys.shuffle(&mut rand::thread_rng());
+            for &k in keys.iter().filter(|&&k| k % 2 == 1) {
+                assert_eq!(map.remove(&k), Some(k));
+                check_invariants(&map.root, t, true);
+            }
+            assert_eq!(map.remove(&1), None);
+            check_invariants(&map.root, t, true);
+            assert_eq!(map.len(), 1000);
+
+            for k in 0..2000 {
+                assert_eq!(map.contains_key(&k), k % 2 == 0);
+            }
+            let sorted: Vec<u32> = map.keys().copied().collect();
+            assert_eq!(sorted, (0..2000).step_by(2).collect::<Vec<_>>());
+
+            // Removing the rest collapses the tree back to an empty leaf
+            for k in (0..2000).step_by(2) {
+                assert_eq!(map.remove(&k), Some(k));
+            }
+            assert!(map.is_empty());
+            assert!(map.root.is_leaf());
+            assert!(map.root.keys.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_borrowed_lookup() {
+        let mut map = BTreeMap::new();
+        map.insert(String::from("b"), 2);
+        map.insert(String::from("a"), 1);
+
+        assert_eq!(map.get("a"), Some(&1));
+        assert_eq!(map.remove("b"), Some(2));
+        assert_eq!(format!("{:?}", map), r#"{"a": 1}"#);
+    }
+
+    #[test]
+    #[should_panic(expected = "minimum degree")]
+    fn test_invalid_min_degree() {
+        let _map: BTreeMap<i32, i32> = BTreeMap::with_min_degree(1);
+    }
+}
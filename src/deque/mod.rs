@@ -0,0 +1,401 @@
+//! A ring-buffer double-ended queue
+//!
+//! This module provides a deque stored in a single contiguous buffer from
+//! the crate's own [`Vector`]. The elements occupy a window of the buffer
+//! that wraps around its end, so both ends can grow and shrink in O(1)
+//! without shifting anything, and without the per-node allocations of a
+//! [`LinkedList`](crate::linked_list::LinkedList).
+
+use core::fmt;
+use core::iter::{FromIterator, FusedIterator};
+use core::mem::MaybeUninit;
+use core::ptr;
+
+use crate::vector::Vector;
+
+// Smallest number of slots allocated when a ring buffer first grows
+const MIN_CAPACITY: usize = 4;
+
+/// A double-ended queue backed by a growable ring buffer
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::deque::RingBuffer;
+///
+/// let mut deque = RingBuffer::new();
+/// deque.push_back(2);
+/// deque.push_back(3);
+/// deque.push_front(1);
+///
+/// assert_eq!(deque.get(0), Some(&1));
+/// assert_eq!(deque.pop_back(), Some(3));
+/// assert_eq!(deque.pop_front(), Some(1));
+/// assert_eq!(deque.len(), 1);
+/// ```
+pub struct RingBuffer<T> {
+    // Every slot of the buffer, initialized or not; `buf.len()` is the
+    // capacity of the ring
+    buf: Vector<MaybeUninit<T>>,
+    // Index of the front element; the back element lives at
+    // `(head + len - 1) % capacity`
+    head: usize,
+    len: usize,
+}
+
+impl<T> Default for RingBuffer<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> RingBuffer<T> {
+    /// Creates a new, empty ring buffer
+    pub fn new() -> Self {
+        RingBuffer {
+            buf: Vector::new(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Creates a new, empty ring buffer with room for `capacity` elements
+    pub fn with_capacity(capacity: usize) -> Self {
+        RingBuffer {
+            buf: Self::slots(capacity),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the ring buffer
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the ring buffer is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements the ring buffer can hold without
+    /// reallocating
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Removes all elements, keeping the allocated buffer
+    pub fn clear(&mut self) {
+        while self.pop_back().is_some() {}
+        self.head = 0;
+    }
+
+    /// Adds an element to the front
+    pub fn push_front(&mut self, element: T) {
+        if self.len == self.capacity() {
+            self.grow();
+        }
+
+        self.head = self.wrap_sub(self.head, 1);
+        self.buf[self.head].write(element);
+        self.len += 1;
+    }
+
+    /// Adds an element to the back
+    pub fn push_back(&mut self, element: T) {
+        if self.len == self.capacity() {
+            self.grow();
+        }
+
+        let slot = self.physical(self.len);
+        self.buf[slot].write(element);
+        self.len += 1;
+    }
+
+    /// Removes the front element and returns it, or `None` if empty
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let slot = self.head;
+        self.head = self.physical(1);
+        self.len -= 1;
+        Some(unsafe { self.buf[slot].assume_init_read() })
+    }
+
+    /// Removes the back element and returns it, or `None` if empty
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        let slot = self.physical(self.len);
+        Some(unsafe { self.buf[slot].assume_init_read() })
+    }
+
+    /// Returns a reference to the front element
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the back element
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    /// Returns a reference to the element at `index`, counting from the front
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        Some(unsafe { self.buf[self.physical(index)].assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the element at `index`, counting from
+    /// the front
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let slot = self.physical(index);
+        Some(unsafe { self.buf[slot].assume_init_mut() })
+    }
+
+    /// Returns an iterator over the elements from front to back
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            ring: self,
+            front: 0,
+            back: self.len,
+        }
+    }
+
+    // Private method to map a logical index to its slot in the buffer
+    fn physical(&self, index: usize) -> usize {
+        let slot = self.head + index;
+        if slot >= self.capacity() {
+            slot - self.capacity()
+        } else {
+            slot
+        }
+    }
+
+    // Private method to step a slot index back by `n`, wrapping around
+    fn wrap_sub(&self, slot: usize, n: usize) -> usize {
+        if slot >= n {
+            slot - n
+        } else {
+            slot + self.capacity() - n
+        }
+    }
+
+    // Private method to allocate a buffer of `capacity` uninitialized slots
+    fn slots(capacity: usize) -> Vector<MaybeUninit<T>> {
+        let mut buf = Vector::with_capacity(capacity);
+        unsafe {
+            // Uninitialized slots are valid values of `MaybeUninit<T>`
+            buf.set_len(capacity);
+        }
+        buf
+    }
+
+    // Private method to double the capacity
+    //
+    // The elements are copied into the new buffer in logical order, so the
+    // wrapped-around part ends up right after the rest and `head` becomes 0.
+    fn grow(&mut self) {
+        let old_capacity = self.capacity();
+        let mut new_buf = Self::slots((old_capacity * 2).max(MIN_CAPACITY));
+
+        // The elements run from `head` to the end of the buffer, then wrap
+        // around to the start
+        let first = self.len.min(old_capacity - self.head);
+        unsafe {
+            let src = self.buf.as_ptr();
+            let dst = new_buf.as_mut_ptr();
+            ptr::copy_nonoverlapping(src.add(self.head), dst, first);
+            ptr::copy_nonoverlapping(src, dst.add(first), self.len - first);
+        }
+
+        // The old buffer only frees its memory: `MaybeUninit` never drops
+        self.buf = new_buf;
+        self.head = 0;
+    }
+}
+
+impl<T> Drop for RingBuffer<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Clone> Clone for RingBuffer<T> {
+    fn clone(&self) -> Self {
+        let mut ring = RingBuffer::with_capacity(self.len);
+        ring.extend(self.iter().cloned());
+        ring
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RingBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for RingBuffer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut ring = RingBuffer::new();
+        ring.extend(iter);
+        ring
+    }
+}
+
+impl<T> Extend<T> for RingBuffer<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            self.push_back(element);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RingBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`RingBuffer`]
+pub struct Iter<'a, T> {
+    ring: &'a RingBuffer<T>,
+    // Logical indices of the next elements to yield from either end
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        self.ring.get(self.front - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.ring.get(self.back)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(ring: &RingBuffer<i32>) -> Vec<i32> {
+        ring.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_push_pop_both_ends() {
+        let mut ring = RingBuffer::new();
+        assert_eq!(ring.pop_front(), None);
+        assert_eq!(ring.pop_back(), None);
+
+        ring.push_back(2);
+        ring.push_back(3);
+        ring.push_front(1);
+        ring.push_front(0);
+        assert_eq!(collect(&ring), [0, 1, 2, 3]);
+        assert_eq!(ring.front(), Some(&0));
+        assert_eq!(ring.back(), Some(&3));
+
+        assert_eq!(ring.pop_front(), Some(0));
+        assert_eq!(ring.pop_back(), Some(3));
+        assert_eq!(ring.pop_back(), Some(2));
+        assert_eq!(ring.pop_back(), Some(1));
+        assert!(ring.is_empty());
+    }
+
+    #[test]
+    fn test_wrap_around_then_grow() {
+        let mut ring = RingBuffer::with_capacity(4);
+        for i in 0..4 {
+            ring.push_back(i);
+        }
+        ring.pop_front();
+        ring.pop_front();
+        // These land at the start of the buffer, after its end
+        ring.push_back(4);
+        ring.push_back(5);
+        assert_eq!(ring.capacity(), 4);
+        assert_eq!(collect(&ring), [2, 3, 4, 5]);
+
+        // Growing has to un-wrap the contents into the new buffer
+        ring.push_back(6);
+        assert_eq!(ring.capacity(), 8);
+        assert_eq!(collect(&ring), [2, 3, 4, 5, 6]);
+        assert_eq!(ring.iter().rev().copied().collect::<Vec<_>>(), [6, 5, 4, 3, 2]);
+
+        // Pushing at the front wraps backwards past slot zero
+        ring.push_front(1);
+        ring.push_front(0);
+        assert_eq!(collect(&ring), [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(ring.get(6), Some(&6));
+        assert_eq!(ring.get(7), None);
+        *ring.get_mut(3).unwrap() *= 10;
+        assert_eq!(collect(&ring), [0, 1, 2, 30, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_grow_while_wrapped_at_front() {
+        let mut ring = RingBuffer::new();
+        for i in (0..20).rev() {
+            ring.push_front(i);
+        }
+        assert_eq!(collect(&ring), (0..20).collect::<Vec<_>>());
+        assert_eq!(ring.iter().len(), 20);
+    }
+
+    #[test]
+    fn test_drops_remaining_elements() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let mut ring = RingBuffer::with_capacity(4);
+        for _ in 0..4 {
+            ring.push_back(Rc::clone(&tracker));
+        }
+        drop(ring.pop_front());
+        ring.push_back(Rc::clone(&tracker));
+        ring.push_front(Rc::clone(&tracker));
+        assert_eq!(Rc::strong_count(&tracker), 6);
+
+        let copy = ring.clone();
+        assert_eq!(Rc::strong_count(&tracker), 11);
+        drop(copy);
+        drop(ring);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+}
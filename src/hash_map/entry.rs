@@ -0,0 +1,268 @@
+//! The entry API for a hash map
+//!
+//! An [`Entry`] is a view into a single slot of a [`HashMap`], found by one
+//! probe. It lets callers inspect, insert or update the value for a key
+//! without hashing and probing a second time.
+
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::mem;
+
+use super::{HashMap, Slot};
+
+/// A view into a single entry of a map, which may be occupied or vacant
+///
+/// This is returned by [`HashMap::entry`].
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::hash_map::HashMap;
+///
+/// let mut counts = HashMap::new();
+/// for word in ["a", "b", "a"] {
+///     counts.entry(word).and_modify(|c| *c += 1).or_insert(1);
+/// }
+///
+/// assert_eq!(counts.get("a"), Some(&2));
+/// assert_eq!(counts.get("b"), Some(&1));
+/// ```
+pub enum Entry<'a, K: Hash + Eq, V, S> {
+    /// An entry holding a value
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    /// An entry with no value yet
+    Vacant(VacantEntry<'a, K, V, S>),
+}
+
+/// A view into an occupied entry of a map
+pub struct OccupiedEntry<'a, K: Hash + Eq, V, S> {
+    map: &'a mut HashMap<K, V, S>,
+    index: usize,
+}
+
+/// A view into a vacant entry of a map
+///
+/// The slot to insert into has already been found, and the table has room
+/// for it, so inserting never probes or resizes.
+pub struct VacantEntry<'a, K: Hash + Eq, V, S> {
+    map: &'a mut HashMap<K, V, S>,
+    key: K,
+    index: usize,
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> HashMap<K, V, S> {
+    /// Returns the entry for `key`, for in-place inspection and update
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        self.reserve_one();
+
+        match self.find(&key) {
+            Ok(index) => Entry::Occupied(OccupiedEntry { map: self, index }),
+            Err(index) => Entry::Vacant(VacantEntry { map: self, key, index }),
+        }
+    }
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher> Entry<'a, K, V, S> {
+    /// Returns the key of this entry
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Inserts `default` if the entry is vacant, and returns a mutable
+    /// reference to the value
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Inserts the result of `default` if the entry is vacant, and returns a
+    /// mutable reference to the value
+    ///
+    /// `default` is only called when the entry is vacant.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Inserts `V::default()` if the entry is vacant, and returns a mutable
+    /// reference to the value
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Calls `f` on the value if the entry is occupied, and returns the
+    /// entry for further chaining
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher> OccupiedEntry<'a, K, V, S> {
+    /// Returns the key of this entry
+    pub fn key(&self) -> &K {
+        self.pair().0
+    }
+
+    /// Returns a reference to the value
+    pub fn get(&self) -> &V {
+        self.pair().1
+    }
+
+    /// Returns a mutable reference to the value
+    pub fn get_mut(&mut self) -> &mut V {
+        match &mut self.map.slots[self.index] {
+            Slot::Occupied(_, v) => v,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Converts the entry into a mutable reference to the value, bound to
+    /// the lifetime of the map
+    pub fn into_mut(self) -> &'a mut V {
+        match &mut self.map.slots[self.index] {
+            Slot::Occupied(_, v) => v,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Replaces the value, returning the old one
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the entry from the map, returning its key and value
+    pub fn remove_entry(self) -> (K, V) {
+        self.map.vacate(self.index)
+    }
+
+    /// Removes the entry from the map, returning its value
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    // Private method to borrow the key and value in the slot
+    fn pair(&self) -> (&K, &V) {
+        match &self.map.slots[self.index] {
+            Slot::Occupied(k, v) => (k, v),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher> VacantEntry<'a, K, V, S> {
+    /// Returns the key that would be used when inserting
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Takes ownership of the key
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Inserts `value` for the entry's key, returning a mutable reference
+    /// to it
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.map.occupy(self.index, self.key, value);
+        match &mut self.map.slots[self.index] {
+            Slot::Occupied(_, v) => v,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + fmt::Debug, V: fmt::Debug, S: BuildHasher> fmt::Debug for Entry<'_, K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Entry::Occupied(entry) => f
+                .debug_struct("OccupiedEntry")
+                .field("key", entry.key())
+                .field("value", entry.get())
+                .finish(),
+            Entry::Vacant(entry) => f.debug_tuple("VacantEntry").field(entry.key()).finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_count() {
+        let text = "the quick brown fox jumps over the lazy dog the end";
+        let mut counts = HashMap::new();
+        for word in text.split_whitespace() {
+            counts.entry(word).and_modify(|c| *c += 1).or_insert(1);
+        }
+
+        assert_eq!(counts.len(), 9);
+        assert_eq!(counts.get("the"), Some(&3));
+        assert_eq!(counts.get("fox"), Some(&1));
+        assert_eq!(counts.get("cat"), None);
+    }
+
+    #[test]
+    fn test_or_insert_with_is_lazy() {
+        let mut map = HashMap::new();
+        map.insert("a", 1);
+
+        let value = map.entry("a").or_insert_with(|| panic!("entry is occupied"));
+        *value += 1;
+        assert_eq!(map.get("a"), Some(&2));
+
+        assert_eq!(*map.entry("b").or_insert_with(|| 5), 5);
+        assert_eq!(*map.entry("c").or_default(), 0);
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn test_occupied_and_vacant() {
+        let mut map = HashMap::new();
+        map.insert(1, "one");
+
+        match map.entry(1) {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.key(), &1);
+                assert_eq!(entry.insert("uno"), "one");
+                assert_eq!(entry.remove(), "uno");
+            }
+            Entry::Vacant(_) => panic!("expected an occupied entry"),
+        }
+        assert!(map.is_empty());
+
+        match map.entry(2) {
+            Entry::Vacant(entry) => {
+                assert_eq!(entry.key(), &2);
+                *entry.insert("two") = "dos";
+            }
+            Entry::Occupied(_) => panic!("expected a vacant entry"),
+        }
+        assert_eq!(map.get(&2), Some(&"dos"));
+    }
+
+    #[test]
+    fn test_entry_grows_table() {
+        let mut map = HashMap::new();
+        for i in 0..1_000 {
+            *map.entry(i % 500).or_insert(0) += 1;
+        }
+        assert_eq!(map.len(), 500);
+        for i in 0..500 {
+            assert_eq!(map.get(&i), Some(&2));
+        }
+    }
+}
@@ -0,0 +1,497 @@
+//! A hash map implementation
+//!
+//! This module provides a hash table using open addressing with linear
+//! probing. All entries live inline in a single slot array stored in the
+//! crate's own [`Vector`], and removed entries leave tombstones behind so
+//! that probe sequences for other keys stay intact.
+
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::mem;
+use std::collections::hash_map::RandomState;
+
+use crate::vector::Vector;
+
+mod entry;
+
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+
+// Smallest non-zero number of slots in the table
+const MIN_SLOTS: usize = 8;
+
+// Load factor used when none is given
+const DEFAULT_LOAD_FACTOR: f64 = 0.75;
+
+// A single slot in the table
+enum Slot<K, V> {
+    // Never used; ends a probe sequence
+    Empty,
+    // Previously held an entry; probing continues past it
+    Deleted,
+    Occupied(K, V),
+}
+
+/// A hash map using open addressing with linear probing
+///
+/// The table grows and rehashes once the fraction of used slots, counting
+/// tombstones left by removals, would exceed the maximum load factor.
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::hash_map::HashMap;
+///
+/// let mut map = HashMap::new();
+/// map.insert("apple", 3);
+/// map.insert("banana", 5);
+///
+/// assert_eq!(map.get("apple"), Some(&3));
+/// assert_eq!(map.remove("apple"), Some(3));
+/// assert!(!map.contains_key("apple"));
+/// assert_eq!(map.len(), 1);
+/// ```
+pub struct HashMap<K: Hash + Eq, V, S = RandomState> {
+    slots: Vector<Slot<K, V>>,
+    len: usize,
+    tombstones: usize,
+    max_load_factor: f64,
+    hash_builder: S,
+}
+
+impl<K: Hash + Eq, V> HashMap<K, V, RandomState> {
+    /// Creates a new, empty map
+    ///
+    /// The map does not allocate until the first insertion.
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+
+    /// Creates a new, empty map that can hold at least `capacity` entries
+    /// without resizing
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> HashMap<K, V, S> {
+    /// Creates a new, empty map that hashes keys with `hash_builder`
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_capacity_and_hasher(0, hash_builder)
+    }
+
+    /// Creates a new, empty map that can hold at least `capacity` entries
+    /// without resizing and hashes keys with `hash_builder`
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        let mut map = HashMap {
+            slots: Vector::new(),
+            len: 0,
+            tombstones: 0,
+            max_load_factor: DEFAULT_LOAD_FACTOR,
+            hash_builder,
+        };
+        if capacity > 0 {
+            map.resize(map.slots_for(capacity));
+        }
+        map
+    }
+
+    /// Sets the maximum load factor, returning the map
+    ///
+    /// The table grows once more than this fraction of its slots are in use.
+    /// Lower values trade memory for shorter probe sequences. The table is
+    /// resized immediately if it is already over the new limit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_load_factor` is not strictly between 0 and 1.
+    pub fn with_max_load_factor(mut self, max_load_factor: f64) -> Self {
+        assert!(
+            max_load_factor > 0.0 && max_load_factor < 1.0,
+            "load factor must be in (0, 1)"
+        );
+
+        self.max_load_factor = max_load_factor;
+        if self.len + self.tombstones > self.max_used() {
+            self.resize(self.slots_for(self.len));
+        }
+        self
+    }
+
+    /// Returns the maximum load factor
+    pub fn max_load_factor(&self) -> f64 {
+        self.max_load_factor
+    }
+
+    /// Returns the number of entries in the map
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the map is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of entries the map can hold without resizing
+    pub fn capacity(&self) -> usize {
+        self.max_used()
+    }
+
+    /// Returns a reference to the map's hasher builder
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    /// Inserts a key-value pair into the map
+    ///
+    /// If the key was already present, its value is replaced and the old
+    /// value is returned. The key itself is not updated.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.reserve_one();
+
+        match self.find(&key) {
+            Ok(index) => match &mut self.slots[index] {
+                Slot::Occupied(_, v) => Some(mem::replace(v, value)),
+                _ => unreachable!(),
+            },
+            Err(index) => {
+                self.occupy(index, key, value);
+                None
+            }
+        }
+    }
+
+    /// Returns a reference to the value for `key`
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key).ok()?;
+        match &self.slots[index] {
+            Slot::Occupied(_, v) => Some(v),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns a mutable reference to the value for `key`
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key).ok()?;
+        match &mut self.slots[index] {
+            Slot::Occupied(_, v) => Some(v),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns whether the map contains `key`
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).is_ok()
+    }
+
+    /// Removes `key` from the map, returning its value if it was present
+    ///
+    /// The slot is marked with a tombstone rather than emptied, so lookups
+    /// for keys that probed past it still succeed.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key).ok()?;
+        Some(self.vacate(index).1)
+    }
+
+    /// Removes every entry from the map, keeping the allocated slots
+    pub fn clear(&mut self) {
+        for slot in self.slots.iter_mut() {
+            *slot = Slot::Empty;
+        }
+        self.len = 0;
+        self.tombstones = 0;
+    }
+
+    // Private method returning how many slots may be in use (including
+    // tombstones) before the table must be resized
+    fn max_used(&self) -> usize {
+        (self.slots.len() as f64 * self.max_load_factor) as usize
+    }
+
+    // Private method returning the smallest power-of-two slot count that
+    // holds `items` entries within the load factor
+    fn slots_for(&self, items: usize) -> usize {
+        let mut slots = MIN_SLOTS;
+        while (slots as f64 * self.max_load_factor) < items as f64 {
+            slots *= 2;
+        }
+        slots
+    }
+
+    // Private method to make room for one more entry
+    //
+    // If tombstones make up most of the used slots, the table is rehashed at
+    // its current size to clear them; otherwise it doubles.
+    fn reserve_one(&mut self) {
+        if self.len + self.tombstones < self.max_used() {
+            return;
+        }
+
+        let slots = if self.len < self.max_used() / 2 {
+            self.slots.len()
+        } else {
+            self.slots_for(self.len + 1).max(self.slots.len() * 2)
+        };
+        self.resize(slots);
+    }
+
+    // Private method to rebuild the table with `new_slots` slots, dropping
+    // all tombstones
+    fn resize(&mut self, new_slots: usize) {
+        debug_assert!(new_slots.is_power_of_two());
+
+        let mut slots = Vector::with_capacity(new_slots);
+        for _ in 0..new_slots {
+            slots.push(Slot::Empty);
+        }
+        let mut old = mem::replace(&mut self.slots, slots);
+        self.tombstones = 0;
+
+        for slot in old.iter_mut() {
+            if let Slot::Occupied(k, v) = mem::replace(slot, Slot::Empty) {
+                let index = self.probe_empty(&k);
+                self.slots[index] = Slot::Occupied(k, v);
+            }
+        }
+    }
+
+    // Private method to store an entry in a free slot found by `find`
+    fn occupy(&mut self, index: usize, key: K, value: V) {
+        if let Slot::Deleted = self.slots[index] {
+            self.tombstones -= 1;
+        }
+        self.slots[index] = Slot::Occupied(key, value);
+        self.len += 1;
+    }
+
+    // Private method to take the entry out of an occupied slot, leaving a
+    // tombstone in its place
+    fn vacate(&mut self, index: usize) -> (K, V) {
+        match mem::replace(&mut self.slots[index], Slot::Deleted) {
+            Slot::Occupied(k, v) => {
+                self.len -= 1;
+                self.tombstones += 1;
+                (k, v)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    // Private method to find the first empty slot for `key`, for use while
+    // rehashing when keys are known to be unique and no tombstones exist
+    fn probe_empty(&self, key: &K) -> usize {
+        let mask = self.slots.len() - 1;
+        let mut index = self.hash_builder.hash_one(key) as usize & mask;
+        while let Slot::Occupied(..) = self.slots[index] {
+            index = (index + 1) & mask;
+        }
+        index
+    }
+
+    // Private method to probe for `key`
+    //
+    // Returns `Ok` with the slot holding `key`, or `Err` with the slot where
+    // it should be inserted: the first tombstone passed, or else the empty
+    // slot that ended the probe. The load factor guarantees an empty slot
+    // exists, so probing always terminates.
+    fn find<Q>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.slots.is_empty() {
+            return Err(0);
+        }
+
+        let mask = self.slots.len() - 1;
+        let mut index = self.hash_builder.hash_one(key) as usize & mask;
+        let mut first_deleted = None;
+        loop {
+            match &self.slots[index] {
+                Slot::Empty => return Err(first_deleted.unwrap_or(index)),
+                Slot::Deleted => {
+                    first_deleted.get_or_insert(index);
+                }
+                Slot::Occupied(k, _) if k.borrow() == key => return Ok(index),
+                Slot::Occupied(..) => {}
+            }
+            index = (index + 1) & mask;
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Default> Default for HashMap<K, V, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K: Hash + Eq, V, S> fmt::Debug for HashMap<K, V, S>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries = self.slots.iter().filter_map(|slot| match slot {
+            Slot::Occupied(k, v) => Some((k, v)),
+            _ => None,
+        });
+        f.debug_map().entries(entries).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hash::{BuildHasherDefault, Hasher};
+
+    // A hasher sending every key to the same slot, to force long probes
+    #[derive(Default)]
+    struct ConstantHasher;
+
+    impl Hasher for ConstantHasher {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, _bytes: &[u8]) {}
+    }
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut map = HashMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.get(&1), None);
+
+        assert_eq!(map.insert(1, "one"), None);
+        assert_eq!(map.insert(2, "two"), None);
+        assert_eq!(map.insert(1, "uno"), Some("one"));
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.get(&1), Some(&"uno"));
+        assert_eq!(map.get(&2), Some(&"two"));
+        assert!(map.contains_key(&2));
+        assert!(!map.contains_key(&3));
+
+        if let Some(v) = map.get_mut(&2) {
+            *v = "dos";
+        }
+        assert_eq!(map.get(&2), Some(&"dos"));
+    }
+
+    #[test]
+    fn test_borrowed_lookup() {
+        let mut map = HashMap::new();
+        map.insert(String::from("key"), 1);
+        assert_eq!(map.get("key"), Some(&1));
+        assert_eq!(map.remove("key"), Some(1));
+    }
+
+    #[test]
+    fn test_many_keys_with_resizes() {
+        let mut map = HashMap::new();
+        for i in 0..10_000 {
+            assert_eq!(map.insert(i, i * 2), None);
+        }
+        assert_eq!(map.len(), 10_000);
+        assert!(map.capacity() >= 10_000);
+
+        for i in (0..10_000).step_by(2) {
+            assert_eq!(map.remove(&i), Some(i * 2));
+        }
+        assert_eq!(map.len(), 5_000);
+
+        for i in 0..10_000 {
+            if i % 2 == 0 {
+                assert_eq!(map.get(&i), None);
+            } else {
+                assert_eq!(map.get(&i), Some(&(i * 2)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_tombstones() {
+        // Every key collides, so all entries share one probe sequence
+        let mut map: HashMap<u32, u32, BuildHasherDefault<ConstantHasher>> = HashMap::default();
+        for i in 0..5 {
+            map.insert(i, i);
+        }
+
+        // Removing from the middle must not cut off the keys after it
+        assert_eq!(map.remove(&1), Some(1));
+        assert_eq!(map.remove(&1), None);
+        assert_eq!(map.get(&4), Some(&4));
+        assert_eq!(map.tombstones, 1);
+
+        // Re-inserting reuses the tombstone without duplicating later keys
+        assert_eq!(map.insert(4, 40), Some(4));
+        assert_eq!(map.insert(1, 10), None);
+        assert_eq!(map.tombstones, 0);
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.get(&1), Some(&10));
+        assert_eq!(map.get(&4), Some(&40));
+    }
+
+    #[test]
+    fn test_churn_rehashes_in_place() {
+        let mut map = HashMap::with_capacity(6);
+        let slots = map.slots.len();
+
+        // Repeated insert/remove fills the table with tombstones, which must
+        // be cleared without growing it
+        for i in 0..1_000 {
+            map.insert(i, i);
+            map.remove(&i);
+        }
+        assert!(map.is_empty());
+        assert_eq!(map.slots.len(), slots);
+        assert!(map.len + map.tombstones <= map.max_used());
+    }
+
+    #[test]
+    fn test_load_factor() {
+        let mut map = HashMap::new().with_max_load_factor(0.5);
+        for i in 0..100 {
+            map.insert(i, ());
+        }
+        assert!(map.len() as f64 <= map.slots.len() as f64 * 0.5);
+
+        // Tightening the load factor resizes straight away
+        let map = map.with_max_load_factor(0.25);
+        assert!(map.len() as f64 <= map.slots.len() as f64 * 0.25);
+        for i in 0..100 {
+            assert!(map.contains_key(&i));
+        }
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut map = HashMap::new();
+        map.insert("a", 1);
+        map.remove("a");
+        map.insert("b", 2);
+
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map.get("b"), None);
+        assert_eq!(map.tombstones, 0);
+    }
+}
@@ -6,32 +6,53 @@
 //! `rust_data_structures` is a collection of high-performance data structures
 //! implemented in Rust. This library aims to provide efficient, well-tested,
 //! and easy-to-use implementations of common and specialized data structures.
+//!
+//! The crate is `no_std` compatible and only needs `alloc`. Collections that
+//! rely on std's random hashers or floating-point math are behind the `std`
+//! feature, which is enabled by default.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 // Module declarations
+pub mod allocator;
 pub mod vector;
 pub mod linked_list;
-
-// TODO: Implement these modules
-// pub mod binary_heap;
-// pub mod hash_map;
-// pub mod avl_tree;
-// pub mod btree;
-// pub mod trie;
-// pub mod bloom_filter;
-// pub mod lru_cache;
+pub mod binary_heap;
+#[cfg(feature = "std")]
+pub mod lru_cache;
+#[cfg(feature = "std")]
+pub mod bloom_filter;
+pub mod trie;
+#[cfg(feature = "std")]
+pub mod hash_map;
+pub mod avl_tree;
+pub mod btree;
+pub mod bitset;
+pub mod deque;
+pub mod slotmap;
+pub mod stable_vec;
 
 // Re-exports for convenient access
 pub use vector::Vector;
 pub use linked_list::LinkedList;
-
-// TODO: Re-export these when implemented
-// pub use binary_heap::BinaryHeap;
-// pub use hash_map::HashMap;
-// pub use avl_tree::AVLTree;
-// pub use btree::BTree;
-// pub use trie::Trie;
-// pub use bloom_filter::BloomFilter;
-// pub use lru_cache::LRUCache;
+#[cfg(feature = "std")]
+pub use linked_list::UniqueList;
+pub use binary_heap::BinaryHeap;
+#[cfg(feature = "std")]
+pub use lru_cache::LruCache;
+#[cfg(feature = "std")]
+pub use bloom_filter::BloomFilter;
+pub use trie::Trie;
+#[cfg(feature = "std")]
+pub use hash_map::HashMap;
+pub use avl_tree::AVLTree;
+pub use btree::BTreeMap;
+pub use bitset::BitSet;
+pub use deque::RingBuffer;
+pub use slotmap::SlotMap;
+pub use stable_vec::StableVec;
 
 /// Library version information
 pub fn version() -> &'static str {
@@ -0,0 +1,431 @@
+//! Cursors over a linked list
+//!
+//! A cursor points at a single element of a [`LinkedList`] and can move
+//! freely in both directions. The mutable cursor allows O(1) insertion and
+//! removal around its position, which is the main reason to reach for a
+//! doubly linked list in the first place.
+//!
+//! Cursors treat the list as circular with an extra "ghost" non-element
+//! between the tail and the head. Moving past either end lands on the ghost,
+//! and moving again wraps around to the other end.
+
+use core::ptr::NonNull;
+
+use super::{LinkedList, Node};
+
+/// A read-only cursor over a linked list
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::linked_list::LinkedList;
+///
+/// let list: LinkedList<i32> = (1..=3).collect();
+/// let mut cursor = list.cursor_front();
+///
+/// cursor.move_next();
+/// assert_eq!(cursor.current(), Some(&2));
+/// assert_eq!(cursor.index(), Some(1));
+/// ```
+pub struct Cursor<'a, T> {
+    current: Option<NonNull<Node<T>>>,
+    index: usize,
+    list: &'a LinkedList<T>,
+}
+
+impl<T> Clone for Cursor<'_, T> {
+    fn clone(&self) -> Self {
+        Cursor {
+            current: self.current,
+            index: self.index,
+            list: self.list,
+        }
+    }
+}
+
+impl<'a, T> Cursor<'a, T> {
+    pub(super) fn new(list: &'a LinkedList<T>, current: Option<NonNull<Node<T>>>, index: usize) -> Self {
+        Cursor { current, index, list }
+    }
+
+    /// Returns the index of the current element, or `None` on the ghost
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Returns a reference to the current element, or `None` on the ghost
+    pub fn current(&self) -> Option<&'a T> {
+        unsafe { self.current.map(|node| &(*node.as_ptr()).element) }
+    }
+
+    /// Returns a reference to the element after the current one
+    pub fn peek_next(&self) -> Option<&'a T> {
+        unsafe {
+            let next = match self.current {
+                Some(node) => (*node.as_ptr()).next,
+                None => self.list.head,
+            };
+            next.map(|node| &(*node.as_ptr()).element)
+        }
+    }
+
+    /// Returns a reference to the element before the current one
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        unsafe {
+            let prev = match self.current {
+                Some(node) => (*node.as_ptr()).prev,
+                None => self.list.tail,
+            };
+            prev.map(|node| &(*node.as_ptr()).element)
+        }
+    }
+
+    /// Moves the cursor to the next element
+    ///
+    /// Moving past the back element lands on the ghost, and moving from the
+    /// ghost lands on the front element.
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(node) => unsafe {
+                self.current = (*node.as_ptr()).next;
+                self.index += 1;
+            },
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Moves the cursor to the previous element
+    ///
+    /// Moving past the front element lands on the ghost, and moving from the
+    /// ghost lands on the back element.
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(node) => unsafe {
+                self.current = (*node.as_ptr()).prev;
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.len);
+            },
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+}
+
+/// A cursor over a linked list that can insert and remove elements
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::linked_list::LinkedList;
+///
+/// let mut list: LinkedList<i32> = (1..=3).collect();
+/// let mut cursor = list.cursor_front_mut();
+///
+/// cursor.move_next();
+/// cursor.insert_before(10);
+/// assert_eq!(cursor.remove_current(), Some(2));
+/// assert_eq!(cursor.current(), Some(&mut 3));
+///
+/// assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 10, 3]);
+/// ```
+pub struct CursorMut<'a, T> {
+    current: Option<NonNull<Node<T>>>,
+    index: usize,
+    list: &'a mut LinkedList<T>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    pub(super) fn new(list: &'a mut LinkedList<T>, current: Option<NonNull<Node<T>>>, index: usize) -> Self {
+        CursorMut { current, index, list }
+    }
+
+    /// Returns the index of the current element, or `None` on the ghost
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Returns a mutable reference to the current element, or `None` on the ghost
+    pub fn current(&mut self) -> Option<&mut T> {
+        unsafe { self.current.map(|node| &mut (*node.as_ptr()).element) }
+    }
+
+    /// Returns a mutable reference to the element after the current one
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        unsafe {
+            let next = match self.current {
+                Some(node) => (*node.as_ptr()).next,
+                None => self.list.head,
+            };
+            next.map(|node| &mut (*node.as_ptr()).element)
+        }
+    }
+
+    /// Returns a mutable reference to the element before the current one
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        unsafe {
+            let prev = match self.current {
+                Some(node) => (*node.as_ptr()).prev,
+                None => self.list.tail,
+            };
+            prev.map(|node| &mut (*node.as_ptr()).element)
+        }
+    }
+
+    /// Moves the cursor to the next element
+    ///
+    /// Moving past the back element lands on the ghost, and moving from the
+    /// ghost lands on the front element.
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(node) => unsafe {
+                self.current = (*node.as_ptr()).next;
+                self.index += 1;
+            },
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Moves the cursor to the previous element
+    ///
+    /// Moving past the front element lands on the ghost, and moving from the
+    /// ghost lands on the back element.
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(node) => unsafe {
+                self.current = (*node.as_ptr()).prev;
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.len);
+            },
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Inserts an element after the current one
+    ///
+    /// If the cursor is on the ghost, the element becomes the new front.
+    pub fn insert_after(&mut self, element: T) {
+        unsafe {
+            self.list.link_after(self.current, element);
+        }
+
+        // The ghost sits at index `len`, which just grew by one
+        if self.current.is_none() {
+            self.index += 1;
+        }
+    }
+
+    /// Inserts an element before the current one
+    ///
+    /// If the cursor is on the ghost, the element becomes the new back.
+    pub fn insert_before(&mut self, element: T) {
+        unsafe {
+            let prev = match self.current {
+                Some(node) => (*node.as_ptr()).prev,
+                None => self.list.tail,
+            };
+            self.list.link_after(prev, element);
+        }
+
+        self.index += 1;
+    }
+
+    /// Moves all elements of `list` in after the current one, keeping their
+    /// order
+    ///
+    /// The nodes are relinked in O(1), whatever the length of `list`. If the
+    /// cursor is on the ghost, the elements become the new front.
+    pub fn splice_after(&mut self, list: LinkedList<T>) {
+        let spliced = unsafe { self.list.splice_after(self.current, list) };
+
+        // The ghost sits at index `len`, which just grew
+        if self.current.is_none() {
+            self.index += spliced;
+        }
+    }
+
+    /// Moves all elements of `list` in before the current one, keeping their
+    /// order
+    ///
+    /// The nodes are relinked in O(1), whatever the length of `list`. If the
+    /// cursor is on the ghost, the elements become the new back.
+    pub fn splice_before(&mut self, list: LinkedList<T>) {
+        let spliced = unsafe {
+            let prev = match self.current {
+                Some(node) => (*node.as_ptr()).prev,
+                None => self.list.tail,
+            };
+            self.list.splice_after(prev, list)
+        };
+
+        self.index += spliced;
+    }
+
+    /// Removes the current element and returns it
+    ///
+    /// The cursor moves on to the next element (or the ghost if the back
+    /// element was removed). Returns `None` if the cursor is on the ghost.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current?;
+        unsafe {
+            self.current = (*node.as_ptr()).next;
+            Some(self.list.unlink_node(node))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(list: &LinkedList<i32>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_cursor_navigation() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        let mut cursor = list.cursor_front();
+
+        assert_eq!(cursor.current(), Some(&1));
+        assert_eq!(cursor.peek_prev(), None);
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.index(), Some(2));
+
+        // Past the back is the ghost, then it wraps to the front
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.peek_next(), Some(&1));
+        assert_eq!(cursor.peek_prev(), Some(&3));
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&1));
+
+        // And backwards from the front
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.index(), Some(2));
+
+        let back = list.cursor_back();
+        assert_eq!(back.current(), Some(&3));
+    }
+
+    #[test]
+    fn test_cursor_mut_insert_in_middle() {
+        let mut list: LinkedList<i32> = (1..=4).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 2));
+
+        cursor.insert_after(25);
+        cursor.insert_before(15);
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(cursor.peek_next(), Some(&mut 25));
+        assert_eq!(cursor.peek_prev(), Some(&mut 15));
+
+        assert_eq!(collect(&list), vec![1, 15, 2, 25, 3, 4]);
+        assert_eq!(list.len(), 6);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![4, 3, 25, 2, 15, 1]);
+    }
+
+    #[test]
+    fn test_cursor_mut_remove_in_middle() {
+        let mut list: LinkedList<i32> = (1..=5).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(cursor.current(), Some(&mut 4));
+        assert_eq!(cursor.index(), Some(2));
+        if let Some(value) = cursor.current() {
+            *value *= 10;
+        }
+
+        assert_eq!(collect(&list), vec![1, 2, 40, 5]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![5, 40, 2, 1]);
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn test_cursor_mut_at_ends() {
+        let mut list = LinkedList::new();
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.remove_current(), None);
+
+        // On the ghost, insert_after adds at the front and insert_before at the back
+        cursor.insert_after(2);
+        cursor.insert_after(1);
+        cursor.insert_before(3);
+        assert_eq!(cursor.current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(cursor.remove_current(), None);
+
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn test_cursor_mut_splice() {
+        let mut list: LinkedList<i32> = (1..=4).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        cursor.splice_after((20..23).collect());
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(cursor.peek_next(), Some(&mut 20));
+
+        cursor.splice_before((10..13).collect());
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(cursor.index(), Some(4));
+
+        // Splicing an empty list changes nothing
+        cursor.splice_after(LinkedList::new());
+
+        assert_eq!(collect(&list), vec![1, 10, 11, 12, 2, 20, 21, 22, 3, 4]);
+        assert_eq!(list.len(), 10);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![4, 3, 22, 21, 20, 2, 12, 11, 10, 1]
+        );
+    }
+
+    #[test]
+    fn test_cursor_mut_splice_at_ghost() {
+        let mut list: LinkedList<i32> = (3..=4).collect();
+        let mut cursor = list.cursor_back_mut();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+
+        cursor.splice_after((1..=2).collect());
+        cursor.splice_before((5..=6).collect());
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(5));
+        assert_eq!(cursor.current(), Some(&mut 6));
+
+        assert_eq!(collect(&list), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&6));
+    }
+}
@@ -3,13 +3,27 @@
 //! This module provides an optimized doubly linked list implementation
 //! with focus on performance and memory efficiency.
 
-use std::ptr::NonNull;
-use std::marker::PhantomData;
-use std::fmt;
-use std::iter::FromIterator;
+use alloc::boxed::Box;
+use core::cmp::Ordering;
+use core::ptr::NonNull;
+use core::marker::PhantomData;
+use core::fmt;
+use core::iter::{FromIterator, FusedIterator};
+use core::ops::{Index, IndexMut};
 
-struct Node<T> {
-    element: T,
+use crate::allocator::Allocator;
+use crate::vector::Vector;
+
+mod cursor;
+#[cfg(feature = "std")]
+mod unique;
+
+pub use cursor::{Cursor, CursorMut};
+#[cfg(feature = "std")]
+pub use unique::UniqueList;
+
+pub(crate) struct Node<T> {
+    pub(crate) element: T,
     next: Option<NonNull<Node<T>>>,
     prev: Option<NonNull<Node<T>>>,
 }
@@ -82,8 +96,37 @@ impl<T> LinkedList<T> {
     }
 
     /// Clears the linked list, removing all elements
+    ///
+    /// The list is detached up front and its nodes are then freed in one
+    /// walk, without relinking `head`, `tail` and `len` after each node. If
+    /// an element's destructor panics, the nodes after it are still freed.
     pub fn clear(&mut self) {
-        while self.pop_front().is_some() {}
+        // Frees the nodes from `0` onwards; only has work left to do if
+        // dropping an element panicked
+        struct FreeRest<T>(Option<NonNull<Node<T>>>);
+
+        impl<T> FreeRest<T> {
+            fn free_all(&mut self) {
+                while let Some(node) = self.0 {
+                    unsafe {
+                        let node = Box::from_raw(node.as_ptr());
+                        self.0 = node.next;
+                        drop(node);
+                    }
+                }
+            }
+        }
+
+        impl<T> Drop for FreeRest<T> {
+            fn drop(&mut self) {
+                self.free_all();
+            }
+        }
+
+        let mut rest = FreeRest(self.head.take());
+        self.tail = None;
+        self.len = 0;
+        rest.free_all();
     }
 
     /// Adds an element to the front of the linked list
@@ -186,6 +229,30 @@ impl<T> LinkedList<T> {
         })
     }
 
+    /// Removes and returns the front element if `pred` returns true for it
+    ///
+    /// If the list is empty or the predicate returns false, the list is left
+    /// unchanged and `None` is returned.
+    pub fn pop_front_if<F: FnOnce(&T) -> bool>(&mut self, pred: F) -> Option<T> {
+        if pred(self.front()?) {
+            self.pop_front()
+        } else {
+            None
+        }
+    }
+
+    /// Removes and returns the back element if `pred` returns true for it
+    ///
+    /// If the list is empty or the predicate returns false, the list is left
+    /// unchanged and `None` is returned.
+    pub fn pop_back_if<F: FnOnce(&T) -> bool>(&mut self, pred: F) -> Option<T> {
+        if pred(self.back()?) {
+            self.pop_back()
+        } else {
+            None
+        }
+    }
+
     /// Returns a reference to the front element
     pub fn front(&self) -> Option<&T> {
         unsafe {
@@ -214,8 +281,688 @@ impl<T> LinkedList<T> {
         }
     }
 
+    /// Returns a reference to the first element
+    ///
+    /// This is an alias for [`front`](LinkedList::front), matching `Vector`.
+    pub fn first(&self) -> Option<&T> {
+        self.front()
+    }
+
+    /// Returns a reference to the last element
+    ///
+    /// This is an alias for [`back`](LinkedList::back), matching `Vector`.
+    pub fn last(&self) -> Option<&T> {
+        self.back()
+    }
+
+    /// Returns a reference to the `n`-th element counting from the front
+    ///
+    /// Unlike [`get`](LinkedList::get), this always walks from the head, so it
+    /// takes `n` steps. Returns `None` if `n >= len`.
+    pub fn nth(&self, n: usize) -> Option<&T> {
+        self.iter().nth(n)
+    }
+
+    /// Returns a reference to the `n`-th element counting from the back
+    ///
+    /// This walks from the tail and takes `n` steps. Returns `None` if
+    /// `n >= len`.
+    pub fn nth_back(&self, n: usize) -> Option<&T> {
+        self.iter().nth_back(n)
+    }
+
+    /// Returns a reference to the element at the given index
+    ///
+    /// The list is walked from whichever end is closer to `index`, so this
+    /// takes at most `len / 2` steps. Returns `None` if `index >= len`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        unsafe { self.node_at(index).map(|node| &(*node.as_ptr()).element) }
+    }
+
+    /// Returns a mutable reference to the element at the given index
+    ///
+    /// The list is walked from whichever end is closer to `index`, so this
+    /// takes at most `len / 2` steps. Returns `None` if `index >= len`.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        unsafe { self.node_at(index).map(|node| &mut (*node.as_ptr()).element) }
+    }
+
+    /// Inserts an element at the given index, shifting later elements back
+    ///
+    /// Inserting at `len` is equivalent to [`push_back`](LinkedList::push_back).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, element: T) {
+        assert!(index <= self.len, "insertion index (is {}) should be <= len (is {})", index, self.len);
+
+        // The new node goes right after the node currently at `index - 1`
+        let prev = match index {
+            0 => None,
+            _ => self.node_at(index - 1),
+        };
+        unsafe {
+            self.link_after(prev, element);
+        }
+    }
+
+    /// Removes the element at the given index and returns it
+    ///
+    /// Returns `None` if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let node = self.node_at(index)?;
+        unsafe { Some(self.unlink_node(node)) }
+    }
+
+    /// Splits the list into two at the given index
+    ///
+    /// Returns a new list containing the elements `at..len`, leaving `0..at`
+    /// in `self`. Finding the split point takes O(min(at, len - at)) but the
+    /// split itself only relinks two nodes; no elements are moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        assert!(at <= self.len, "cannot split off at a nonexistent index");
+
+        if at == 0 {
+            return core::mem::take(self);
+        }
+        if at == self.len {
+            return LinkedList::new();
+        }
+
+        // `at` is in bounds here, so the split node always exists
+        let new_head = self.node_at(at).unwrap();
+        unsafe {
+            let new_tail = (*new_head.as_ptr()).prev;
+            (*new_head.as_ptr()).prev = None;
+            if let Some(new_tail) = new_tail {
+                (*new_tail.as_ptr()).next = None;
+            }
+
+            let second = LinkedList {
+                head: Some(new_head),
+                tail: self.tail,
+                len: self.len - at,
+                marker: PhantomData,
+            };
+
+            self.tail = new_tail;
+            self.len = at;
+            second
+        }
+    }
+
+    /// Shortens the list to its first `len` elements, dropping the rest
+    ///
+    /// The dropped nodes are split off in one relink and then freed. Does
+    /// nothing if `len` is greater than or equal to the list's length.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            drop(self.split_off(len));
+        }
+    }
+
+    /// Moves all elements from `other` to the end of the list
+    ///
+    /// This splices the nodes of `other` onto the tail in O(1); no nodes are
+    /// reallocated. `other` is left empty and can be reused.
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        match self.tail {
+            None => core::mem::swap(self, other),
+            Some(tail) => {
+                if let Some(other_head) = other.head.take() {
+                    unsafe {
+                        (*tail.as_ptr()).next = Some(other_head);
+                        (*other_head.as_ptr()).prev = Some(tail);
+                    }
+
+                    self.tail = other.tail.take();
+                    self.len += core::mem::replace(&mut other.len, 0);
+                }
+            }
+        }
+    }
+
+    /// Clones every element of `other` onto the back of the list, in order
+    ///
+    /// Unlike [`append`](LinkedList::append), which moves the nodes in O(1),
+    /// this allocates a new node per element and leaves `other` intact.
+    pub fn extend_from_list(&mut self, other: &LinkedList<T>)
+    where
+        T: Clone,
+    {
+        self.extend(other.iter().cloned());
+    }
+
+    /// Prepends the elements of `iter`, keeping them in their original order
+    ///
+    /// The elements are collected into a temporary list whose nodes are then
+    /// spliced in front of the head, so no node is moved twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([3, 4]);
+    /// list.extend_front([1, 2]);
+    /// assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// ```
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut front: LinkedList<T> = iter.into_iter().collect();
+        front.append(self);
+        core::mem::swap(self, &mut front);
+    }
+
+    /// Rotates the list in place so that the first `n` elements move to the
+    /// back
+    ///
+    /// Finding the pivot takes O(min(n, len - n)); the rotation itself only
+    /// relinks the head and tail nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// list.rotate_left(2);
+    /// assert_eq!(list.iter().copied().collect::<Vec<_>>(), [3, 4, 5, 1, 2]);
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(n <= self.len, "rotation amount (is {}) should be <= len (is {})", n, self.len);
+
+        let mut rotated = self.split_off(n);
+        rotated.append(self);
+        core::mem::swap(self, &mut rotated);
+    }
+
+    /// Rotates the list in place so that the last `n` elements move to the
+    /// front
+    ///
+    /// Finding the pivot takes O(min(n, len - n)); the rotation itself only
+    /// relinks the head and tail nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n > len`.
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(n <= self.len, "rotation amount (is {}) should be <= len (is {})", n, self.len);
+
+        self.rotate_left(self.len - n);
+    }
+
+    /// Reverses the order of the list in place
+    ///
+    /// Only the `next`/`prev` links are rewired; no elements are moved and
+    /// nothing is allocated.
+    pub fn reverse(&mut self) {
+        let mut current = self.head;
+        while let Some(node) = current {
+            unsafe {
+                let node = &mut *node.as_ptr();
+                core::mem::swap(&mut node.next, &mut node.prev);
+                // After the swap, the old `next` lives in `prev`
+                current = node.prev;
+            }
+        }
+
+        core::mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Swaps the elements at indices `a` and `b`
+    ///
+    /// The nodes stay where they are; only their elements are exchanged.
+    /// Each node is found by walking from the nearer end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let len = self.len;
+        let find = |index| {
+            self.node_at(index)
+                .unwrap_or_else(|| panic!("index out of bounds: the len is {} but the index is {}", len, index))
+        };
+        let (node_a, node_b) = (find(a), find(b));
+
+        unsafe {
+            // Raw pointers rather than references, since `a` and `b` may be
+            // the same node and `core::ptr::swap` allows them to overlap
+            core::ptr::swap(&raw mut (*node_a.as_ptr()).element, &raw mut (*node_b.as_ptr()).element);
+        }
+    }
+
+    /// Retains only the elements for which `f` returns true
+    ///
+    /// Rejected nodes are unlinked and freed in a single pass; the remaining
+    /// elements keep their order.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut current = self.head;
+        while let Some(node) = current {
+            unsafe {
+                current = (*node.as_ptr()).next;
+                if !f(&(*node.as_ptr()).element) {
+                    self.unlink_node(node);
+                }
+            }
+        }
+    }
+
+    /// Removes the elements matching `pred`, returning them through an
+    /// iterator
+    ///
+    /// The list is walked once from front to back. Each element for which
+    /// `pred` returns `true` is unlinked and yielded by value; the others stay
+    /// in place. If the iterator is dropped before it is exhausted, the
+    /// remaining matches are still removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = (0..6).collect();
+    /// let odds: Vec<i32> = list.drain_filter(|x| *x % 2 == 1).collect();
+    ///
+    /// assert_eq!(odds, [1, 3, 5]);
+    /// assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 2, 4]);
+    /// ```
+    pub fn drain_filter<F: FnMut(&mut T) -> bool>(&mut self, pred: F) -> DrainFilter<'_, T, F> {
+        DrainFilter {
+            current: self.head,
+            list: self,
+            pred,
+        }
+    }
+
+    /// Sorts the list in ascending order
+    ///
+    /// This is a stable merge sort that relinks nodes instead of moving
+    /// elements, so it runs in O(n log n) without requiring `T: Clone`.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the list with a comparator function
+    ///
+    /// This is a stable merge sort that relinks nodes instead of moving
+    /// elements, so it runs in O(n log n) without requiring `T: Clone`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = vec![3, 1, 2].into_iter().collect();
+    /// list.sort_by(|a, b| b.cmp(a));
+    ///
+    /// assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        if self.len < 2 {
+            return;
+        }
+
+        // Detach the nodes while sorting, so a panicking comparator leaks
+        // them instead of leaving the list half-linked
+        let len = core::mem::replace(&mut self.len, 0);
+        let head = self.head.take().unwrap();
+        self.tail = None;
+
+        unsafe {
+            let head = Self::merge_sort(head, len, &mut compare);
+            self.attach_run(head, len);
+        }
+    }
+
+    /// Merges the sorted list `other` into this sorted list
+    ///
+    /// Both lists must already be sorted in ascending order; the result is
+    /// then sorted as well, with elements of `self` placed before equal
+    /// elements of `other`. Nodes are relinked in O(n + m) without allocating
+    /// or moving any elements. If either input is unsorted the elements are
+    /// still all kept, but their order is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([1, 3, 5]);
+    /// list.merge_sorted(LinkedList::from([2, 4, 6]));
+    /// assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn merge_sorted(&mut self, mut other: LinkedList<T>)
+    where
+        T: Ord,
+    {
+        if other.is_empty() {
+            return;
+        }
+        if self.is_empty() {
+            core::mem::swap(self, &mut other);
+            return;
+        }
+
+        // Detach both runs first, so a panicking `cmp` leaks the nodes
+        // instead of leaving either list half-linked
+        let len = core::mem::replace(&mut self.len, 0) + core::mem::replace(&mut other.len, 0);
+        let left = self.head.take().unwrap();
+        let right = other.head.take().unwrap();
+        self.tail = None;
+        other.tail = None;
+
+        unsafe {
+            let head = Self::merge(left, right, &mut T::cmp);
+            self.attach_run(head, len);
+        }
+    }
+
+    // Private method to sort the `len` nodes reachable from `head` via `next`
+    //
+    // Returns the head of the sorted run, whose last node has no `next`.
+    unsafe fn merge_sort<F: FnMut(&T, &T) -> Ordering>(
+        head: NonNull<Node<T>>,
+        len: usize,
+        compare: &mut F,
+    ) -> NonNull<Node<T>> {
+        if len == 1 {
+            (*head.as_ptr()).next = None;
+            return head;
+        }
+
+        // Find the start of the second half before the first half is cut off
+        let mid = len / 2;
+        let mut right = head;
+        for _ in 0..mid {
+            right = (*right.as_ptr()).next.unwrap();
+        }
+
+        let left = Self::merge_sort(head, mid, compare);
+        let right = Self::merge_sort(right, len - mid, compare);
+        Self::merge(left, right, compare)
+    }
+
+    // Private method to merge two sorted runs, preferring `left` on ties
+    unsafe fn merge<F: FnMut(&T, &T) -> Ordering>(
+        left: NonNull<Node<T>>,
+        right: NonNull<Node<T>>,
+        compare: &mut F,
+    ) -> NonNull<Node<T>> {
+        let mut left = Some(left);
+        let mut right = Some(right);
+        let mut head: Option<NonNull<Node<T>>> = None;
+        let mut tail: Option<NonNull<Node<T>>> = None;
+
+        while let (Some(l), Some(r)) = (left, right) {
+            let next = if compare(&(*r.as_ptr()).element, &(*l.as_ptr()).element) == Ordering::Less {
+                right = (*r.as_ptr()).next;
+                r
+            } else {
+                left = (*l.as_ptr()).next;
+                l
+            };
+
+            match tail {
+                Some(tail) => (*tail.as_ptr()).next = Some(next),
+                None => head = Some(next),
+            }
+            tail = Some(next);
+        }
+
+        // One run is exhausted, so the rest of the other is already in order
+        let rest = left.or(right);
+        match tail {
+            Some(tail) => (*tail.as_ptr()).next = rest,
+            None => head = rest,
+        }
+
+        head.unwrap()
+    }
+
+    // Private method to install the `len` nodes reachable from `head` via
+    // `next` as the contents of this (detached, empty) list
+    //
+    // Merging only maintains `next` links, so `prev` is rebuilt on the way.
+    unsafe fn attach_run(&mut self, head: NonNull<Node<T>>, len: usize) {
+        let mut prev = None;
+        let mut current = Some(head);
+        while let Some(node) = current {
+            (*node.as_ptr()).prev = prev;
+            prev = Some(node);
+            current = (*node.as_ptr()).next;
+        }
+
+        self.head = Some(head);
+        self.tail = prev;
+        self.len = len;
+    }
+
+    // Private method to find the node at `index`, walking from the nearer end
+    fn node_at(&self, index: usize) -> Option<NonNull<Node<T>>> {
+        if index >= self.len {
+            return None;
+        }
+
+        unsafe {
+            if index < self.len / 2 {
+                let mut current = self.head;
+                for _ in 0..index {
+                    current = current.and_then(|node| (*node.as_ptr()).next);
+                }
+                current
+            } else {
+                let mut current = self.tail;
+                for _ in index + 1..self.len {
+                    current = current.and_then(|node| (*node.as_ptr()).prev);
+                }
+                current
+            }
+        }
+    }
+
+    // Private method to link a new node holding `element` right after `prev`,
+    // or at the front of the list when `prev` is `None`
+    //
+    // The caller must guarantee that `prev` belongs to this list.
+    unsafe fn link_after(&mut self, prev: Option<NonNull<Node<T>>>, element: T) -> NonNull<Node<T>> {
+        let next = match prev {
+            Some(prev) => (*prev.as_ptr()).next,
+            None => self.head,
+        };
+
+        let node = Box::new(Node { element, next, prev });
+        let node_ptr = NonNull::new(Box::into_raw(node)).unwrap();
+
+        // Hook the new node in after `prev` (or as the new head)
+        match prev {
+            Some(prev) => (*prev.as_ptr()).next = Some(node_ptr),
+            None => self.head = Some(node_ptr),
+        }
+
+        // Hook the new node in before `next` (or as the new tail)
+        match next {
+            Some(next) => (*next.as_ptr()).prev = Some(node_ptr),
+            None => self.tail = Some(node_ptr),
+        }
+
+        self.len += 1;
+        node_ptr
+    }
+
+    // Private method to link all nodes of `other` right after `prev`, or at
+    // the front of the list when `prev` is `None`, and return how many were
+    // linked
+    //
+    // The caller must guarantee that `prev` belongs to this list.
+    unsafe fn splice_after(&mut self, prev: Option<NonNull<Node<T>>>, mut other: LinkedList<T>) -> usize {
+        let (Some(first), Some(last)) = (other.head.take(), other.tail.take()) else {
+            return 0;
+        };
+        let len = core::mem::replace(&mut other.len, 0);
+
+        let next = match prev {
+            Some(prev) => (*prev.as_ptr()).next,
+            None => self.head,
+        };
+        (*first.as_ptr()).prev = prev;
+        (*last.as_ptr()).next = next;
+
+        match prev {
+            Some(prev) => (*prev.as_ptr()).next = Some(first),
+            None => self.head = Some(first),
+        }
+        match next {
+            Some(next) => (*next.as_ptr()).prev = Some(last),
+            None => self.tail = Some(last),
+        }
+
+        self.len += len;
+        len
+    }
+
+    // Crate-internal method to add an element at the front and return a
+    // handle to its node, which stays valid until the node is removed
+    #[cfg(feature = "std")]
+    pub(crate) fn push_front_node(&mut self, element: T) -> NonNull<Node<T>> {
+        unsafe { self.link_after(None, element) }
+    }
+
+    // Crate-internal method to return a handle to the back node
+    #[cfg(feature = "std")]
+    pub(crate) fn back_node(&self) -> Option<NonNull<Node<T>>> {
+        self.tail
+    }
+
+    // Crate-internal method to remove a node by handle and return its element
+    //
+    // The caller must guarantee that `node` belongs to this list.
+    #[cfg(feature = "std")]
+    pub(crate) unsafe fn remove_node(&mut self, node: NonNull<Node<T>>) -> T {
+        self.unlink_node(node)
+    }
+
+    // Crate-internal method to relink a node at the front of the list in O(1)
+    //
+    // The caller must guarantee that `node` belongs to this list.
+    #[cfg(feature = "std")]
+    pub(crate) unsafe fn move_node_to_front(&mut self, node: NonNull<Node<T>>) {
+        if self.head == Some(node) {
+            return;
+        }
+
+        // The node is not the head, so it always has a previous node
+        let prev = (*node.as_ptr()).prev;
+        let next = (*node.as_ptr()).next;
+        if let Some(prev) = prev {
+            (*prev.as_ptr()).next = next;
+        }
+        match next {
+            Some(next) => (*next.as_ptr()).prev = prev,
+            None => self.tail = prev,
+        }
+
+        (*node.as_ptr()).prev = None;
+        (*node.as_ptr()).next = self.head;
+        if let Some(head) = self.head {
+            (*head.as_ptr()).prev = Some(node);
+        }
+        self.head = Some(node);
+    }
+
+    // Private method to detach a node from the list and return its element
+    //
+    // The caller must guarantee that `node` belongs to this list.
+    unsafe fn unlink_node(&mut self, node: NonNull<Node<T>>) -> T {
+        let node = Box::from_raw(node.as_ptr());
+
+        // Point the previous node (or the head) past the removed node
+        match node.prev {
+            Some(prev) => (*prev.as_ptr()).next = node.next,
+            None => self.head = node.next,
+        }
+
+        // Point the next node (or the tail) back past the removed node
+        match node.next {
+            Some(next) => (*next.as_ptr()).prev = node.prev,
+            None => self.tail = node.prev,
+        }
+
+        self.len -= 1;
+        node.element
+    }
+
+    /// Returns a new list holding `f` applied to each element, in order
+    ///
+    /// Each result is pushed onto the back of a fresh list, so this
+    /// allocates one node per element and leaves `self` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// let doubled = list.map(|x| x * 2);
+    /// assert_eq!(doubled.iter().copied().collect::<Vec<_>>(), [2, 4, 6]);
+    /// ```
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> LinkedList<U> {
+        self.iter().map(f).collect()
+    }
+
+    /// Returns the first non-`None` result of `f`, scanning from the front
+    ///
+    /// Stops at the first match, so `f` is not called on the elements after
+    /// it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from(["a", "12", "b", "34"]);
+    /// assert_eq!(list.find_map(|s| s.parse::<i32>().ok()), Some(12));
+    /// ```
+    pub fn find_map<U, F: FnMut(&T) -> Option<U>>(&self, f: F) -> Option<U> {
+        self.iter().find_map(f)
+    }
+
+    /// Consumes the list and moves its elements, front to back, into a new
+    /// vector
+    ///
+    /// The vector is allocated once with room for exactly `len` elements,
+    /// and each node is freed as soon as its element has been moved out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::linked_list::LinkedList;
+    ///
+    /// let vec = LinkedList::from([1, 2, 3]).into_vector();
+    /// assert_eq!(&*vec, &[1, 2, 3]);
+    /// ```
+    pub fn into_vector(self) -> Vector<T> {
+        let mut vec = Vector::with_capacity(self.len);
+        for element in self {
+            vec.push(element);
+        }
+        vec
+    }
+
     /// Returns an iterator over the linked list
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             head: self.head,
             tail: self.tail,
@@ -224,8 +971,37 @@ impl<T> LinkedList<T> {
         }
     }
 
+    /// Returns an iterator over the elements from `index` to the back
+    ///
+    /// The starting node is found by walking from whichever end is closer,
+    /// so this takes at most `len / 2` steps. Starting at `len` gives an
+    /// empty iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// assert_eq!(list.iter_from(2).copied().collect::<Vec<_>>(), [3, 4, 5]);
+    /// ```
+    pub fn iter_from(&self, index: usize) -> Iter<'_, T> {
+        assert!(index <= self.len, "start index (is {}) should be <= len (is {})", index, self.len);
+
+        Iter {
+            head: self.node_at(index),
+            tail: self.tail,
+            len: self.len - index,
+            marker: PhantomData,
+        }
+    }
+
     /// Returns a mutable iterator over the linked list
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         IterMut {
             head: self.head,
             tail: self.tail,
@@ -233,11 +1009,75 @@ impl<T> LinkedList<T> {
             marker: PhantomData,
         }
     }
+
+    /// Returns a cursor positioned at the front element
+    ///
+    /// If the list is empty the cursor points at the "ghost" non-element.
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor::new(self, self.head, 0)
+    }
+
+    /// Returns a cursor positioned at the back element
+    ///
+    /// If the list is empty the cursor points at the "ghost" non-element.
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor::new(self, self.tail, self.len.saturating_sub(1))
+    }
+
+    /// Returns a cursor positioned at the front element that can edit the list
+    ///
+    /// If the list is empty the cursor points at the "ghost" non-element.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let head = self.head;
+        CursorMut::new(self, head, 0)
+    }
+
+    /// Returns a cursor positioned at the back element that can edit the list
+    ///
+    /// If the list is empty the cursor points at the "ghost" non-element.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let (tail, index) = (self.tail, self.len.saturating_sub(1));
+        CursorMut::new(self, tail, index)
+    }
+}
+
+impl<T: PartialEq> LinkedList<T> {
+    /// Returns whether the list contains an element equal to `x`
+    pub fn contains(&self, x: &T) -> bool {
+        self.iter().any(|item| item == x)
+    }
+
+    /// Returns the index of the first element equal to `x`, scanning from
+    /// the front
+    pub fn position(&self, x: &T) -> Option<usize> {
+        self.iter().position(|item| item == x)
+    }
+
+    /// Splits the list before the first element equal to `value`
+    ///
+    /// That element and everything after it are moved into the returned
+    /// list, leaving the elements before it in `self`. Returns `None`, and
+    /// leaves the list untouched, if no element matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from(["GET", "/", ";", "body"]);
+    /// let rest = list.split_at_value(&";").unwrap();
+    /// assert_eq!(list.iter().copied().collect::<Vec<_>>(), ["GET", "/"]);
+    /// assert_eq!(rest.iter().copied().collect::<Vec<_>>(), [";", "body"]);
+    /// ```
+    pub fn split_at_value(&mut self, value: &T) -> Option<LinkedList<T>> {
+        let at = self.position(value)?;
+        Some(self.split_off(at))
+    }
 }
 
 impl<T> Drop for LinkedList<T> {
     fn drop(&mut self) {
-        while self.pop_front().is_some() {}
+        self.clear();
     }
 }
 
@@ -249,6 +1089,24 @@ impl<T: Clone> Clone for LinkedList<T> {
         }
         new_list
     }
+
+    /// Overwrites `self` with a copy of `source`, reusing existing nodes
+    ///
+    /// Elements are cloned into the nodes the lists have in common, so only
+    /// the difference in length is allocated or freed.
+    fn clone_from(&mut self, source: &Self) {
+        while self.len > source.len {
+            self.pop_back();
+        }
+
+        let mut source_iter = source.iter();
+        for (item, source_item) in self.iter_mut().zip(&mut source_iter) {
+            item.clone_from(source_item);
+        }
+        for item in source_iter {
+            self.push_back(item.clone());
+        }
+    }
 }
 
 impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
@@ -257,6 +1115,28 @@ impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
     }
 }
 
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+// Lists and vectors compare equal when they hold equal elements in the same
+// order, regardless of how those elements are stored
+impl<T: PartialEq, A: Allocator> PartialEq<Vector<T, A>> for LinkedList<T> {
+    fn eq(&self, other: &Vector<T, A>) -> bool {
+        self.len == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq, A: Allocator> PartialEq<LinkedList<T>> for Vector<T, A> {
+    fn eq(&self, other: &LinkedList<T>) -> bool {
+        other == self
+    }
+}
+
 impl<T> FromIterator<T> for LinkedList<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut list = LinkedList::new();
@@ -275,6 +1155,55 @@ impl<T> Extend<T> for LinkedList<T> {
     }
 }
 
+impl<T> Index<usize> for LinkedList<T> {
+    type Output = T;
+
+    /// Returns a reference to the element at `index`
+    ///
+    /// This is O(n): the list is walked from whichever end is closer, taking
+    /// at most `len / 2` steps. Prefer [`iter`](LinkedList::iter) when visiting
+    /// elements in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    fn index(&self, index: usize) -> &T {
+        let len = self.len;
+        self.get(index)
+            .unwrap_or_else(|| panic!("index out of bounds: the len is {} but the index is {}", len, index))
+    }
+}
+
+impl<T> IndexMut<usize> for LinkedList<T> {
+    /// Returns a mutable reference to the element at `index`
+    ///
+    /// This is O(n): the list is walked from whichever end is closer, taking
+    /// at most `len / 2` steps. Prefer [`iter_mut`](LinkedList::iter_mut) when
+    /// visiting elements in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("index out of bounds: the len is {} but the index is {}", len, index))
+    }
+}
+
+impl<T: Clone> From<&[T]> for LinkedList<T> {
+    fn from(slice: &[T]) -> Self {
+        slice.iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+    fn from(array: [T; N]) -> Self {
+        // Arrays iterate by value, so the elements are moved, not cloned
+        array.into_iter().collect()
+    }
+}
+
 /// An iterator over the linked list
 pub struct Iter<'a, T> {
     head: Option<NonNull<Node<T>>>,
@@ -329,6 +1258,10 @@ impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
     }
 }
 
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
 /// A mutable iterator over the linked list
 pub struct IterMut<'a, T> {
     head: Option<NonNull<Node<T>>>,
@@ -383,27 +1316,114 @@ impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
 
-    #[test]
-    fn test_push_pop_front() {
-        let mut list = LinkedList::new();
-        
-        // Push some elements onto the front
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
-        
-        assert_eq!(list.len(), 3);
-        
-        // Pop them off in the expected order
-        assert_eq!(list.pop_front(), Some(1));
-        assert_eq!(list.pop_front(), Some(2));
-        assert_eq!(list.pop_front(), Some(3));
-        assert_eq!(list.pop_front(), None);
-        
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+/// An owning iterator over the linked list
+///
+/// Any elements that have not been yielded are dropped along with the
+/// iterator.
+pub struct IntoIter<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.list).finish()
+    }
+}
+
+/// An iterator that removes the elements of a linked list matching a
+/// predicate
+///
+/// This is created by [`LinkedList::drain_filter`].
+pub struct DrainFilter<'a, T, F: FnMut(&mut T) -> bool> {
+    list: &'a mut LinkedList<T>,
+    // The next node to test
+    current: Option<NonNull<Node<T>>>,
+    pred: F,
+}
+
+impl<T, F: FnMut(&mut T) -> bool> Iterator for DrainFilter<'_, T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.current {
+            unsafe {
+                self.current = (*node.as_ptr()).next;
+                if (self.pred)(&mut (*node.as_ptr()).element) {
+                    return Some(self.list.unlink_node(node));
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.list.len))
+    }
+}
+
+impl<T, F: FnMut(&mut T) -> bool> Drop for DrainFilter<'_, T, F> {
+    fn drop(&mut self) {
+        // Finish removing the matching elements
+        self.for_each(drop);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_pop_front() {
+        let mut list = LinkedList::new();
+        
+        // Push some elements onto the front
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+        
+        assert_eq!(list.len(), 3);
+        
+        // Pop them off in the expected order
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), None);
+        
         assert_eq!(list.len(), 0);
     }
 
@@ -443,6 +1463,26 @@ mod tests {
         assert_eq!(list.pop_back(), None);
     }
 
+    #[test]
+    fn test_pop_if() {
+        let mut list: LinkedList<i32> = (1..=4).collect();
+
+        assert_eq!(list.pop_front_if(|&x| x == 1), Some(1));
+        assert_eq!(list.pop_back_if(|&x| x > 3), Some(4));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [2, 3]);
+
+        // A rejected element stays where it is
+        assert_eq!(list.pop_front_if(|&x| x > 2), None);
+        assert_eq!(list.pop_back_if(|&x| x < 3), None);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.front(), Some(&2));
+        assert_eq!(list.back(), Some(&3));
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.pop_front_if(|_| true), None);
+        assert_eq!(empty.pop_back_if(|_| true), None);
+    }
+
     #[test]
     fn test_iter() {
         let mut list = LinkedList::new();
@@ -474,4 +1514,747 @@ mod tests {
         assert_eq!(iter.next(), Some(&6));
         assert_eq!(iter.next(), None);
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_get() {
+        let mut list: LinkedList<i32> = (0..5).collect();
+
+        assert_eq!(list.get(0), Some(&0));
+        assert_eq!(list.get(2), Some(&2));
+        assert_eq!(list.get(4), Some(&4));
+        assert_eq!(list.get(5), None);
+        assert_eq!(list.get(usize::MAX), None);
+
+        *list.get_mut(1).unwrap() = 10;
+        *list.get_mut(3).unwrap() = 30;
+        assert_eq!(list.get_mut(5), None);
+
+        let items: Vec<_> = list.iter().copied().collect();
+        assert_eq!(items, vec![0, 10, 2, 30, 4]);
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.get(0), None);
+    }
+
+    #[test]
+    fn test_nth() {
+        let list: LinkedList<i32> = (0..5).collect();
+
+        assert_eq!(list.nth(0), Some(&0));
+        assert_eq!(list.nth(3), Some(&3));
+        assert_eq!(list.nth(4), Some(&4));
+        assert_eq!(list.nth(5), None);
+
+        assert_eq!(list.nth_back(0), Some(&4));
+        assert_eq!(list.nth_back(3), Some(&1));
+        assert_eq!(list.nth_back(4), Some(&0));
+        assert_eq!(list.nth_back(5), None);
+
+        assert_eq!(list.first(), Some(&0));
+        assert_eq!(list.last(), Some(&4));
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.nth(0), None);
+        assert_eq!(empty.nth_back(0), None);
+        assert_eq!(empty.first(), None);
+        assert_eq!(empty.last(), None);
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut list = LinkedList::new();
+        list.insert(0, 2);
+        list.insert(0, 0);
+        list.insert(1, 1);
+        list.insert(3, 4);
+        list.insert(3, 3);
+
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1, 0]);
+
+        assert_eq!(list.remove(5), None);
+        assert_eq!(list.remove(2), Some(2));
+        assert_eq!(list.remove(0), Some(0));
+        assert_eq!(list.remove(2), Some(4));
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![3, 1]);
+
+        assert_eq!(list.remove(1), Some(3));
+        assert_eq!(list.remove(0), Some(1));
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_out_of_bounds() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.insert(2, 2);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let list: LinkedList<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+
+        let mut collected = Vec::new();
+        for item in list {
+            collected.push(item);
+        }
+        assert_eq!(collected, vec!["a", "b", "c"]);
+
+        let list: LinkedList<i32> = (1..=4).collect();
+        let mut iter = list.into_iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_into_iter_partial_drop() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let list: LinkedList<Rc<()>> = (0..5).map(|_| Rc::clone(&tracker)).collect();
+        assert_eq!(Rc::strong_count(&tracker), 6);
+
+        let mut iter = list.into_iter();
+        drop(iter.next());
+        drop(iter.next_back());
+        assert_eq!(Rc::strong_count(&tracker), 4);
+
+        // The remaining nodes are freed when the iterator is dropped
+        drop(iter);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    fn test_split_off() {
+        fn both_ways(list: &LinkedList<i32>) -> (Vec<i32>, Vec<i32>) {
+            (
+                list.iter().copied().collect(),
+                list.iter().rev().copied().collect(),
+            )
+        }
+
+        let mut list: LinkedList<i32> = (0..5).collect();
+        let mut second = list.split_off(2);
+        assert_eq!(list.len(), 2);
+        assert_eq!(second.len(), 3);
+        assert_eq!(both_ways(&list), (vec![0, 1], vec![1, 0]));
+        assert_eq!(both_ways(&second), (vec![2, 3, 4], vec![4, 3, 2]));
+
+        // Both halves stay independently usable
+        list.push_back(10);
+        second.push_front(20);
+        assert_eq!(both_ways(&list), (vec![0, 1, 10], vec![10, 1, 0]));
+        assert_eq!(both_ways(&second), (vec![20, 2, 3, 4], vec![4, 3, 2, 20]));
+
+        let all = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(both_ways(&all), (vec![0, 1, 10], vec![10, 1, 0]));
+
+        let mut second = second;
+        let none = second.split_off(4);
+        assert!(none.is_empty());
+        assert_eq!(second.len(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_off_out_of_bounds() {
+        let mut list: LinkedList<i32> = (0..3).collect();
+        list.split_off(4);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut list: LinkedList<i32> = (0..3).collect();
+        let mut other: LinkedList<i32> = (3..6).collect();
+
+        list.append(&mut other);
+        assert_eq!(list.len(), 6);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![5, 4, 3, 2, 1, 0]);
+
+        assert!(other.is_empty());
+        assert_eq!(other.front(), None);
+        assert_eq!(other.back(), None);
+        other.push_back(7);
+        assert_eq!(other.pop_front(), Some(7));
+
+        // Appending into an empty list takes over the other's nodes
+        let mut empty = LinkedList::new();
+        empty.append(&mut list);
+        assert_eq!(empty.len(), 6);
+        assert!(list.is_empty());
+
+        // Appending an empty list is a no-op
+        empty.append(&mut list);
+        assert_eq!(empty.len(), 6);
+        assert_eq!(empty.back(), Some(&5));
+    }
+
+    #[test]
+    fn test_extend_front() {
+        let mut list = LinkedList::from([3, 4]);
+        list.extend_front([1, 2]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [4, 3, 2, 1]);
+        assert_eq!(list.len(), 4);
+
+        list.extend_front(core::iter::empty());
+        assert_eq!(list.len(), 4);
+
+        let mut empty = LinkedList::new();
+        empty.extend_front(0..3);
+        assert_eq!(empty.front(), Some(&0));
+        assert_eq!(empty.back(), Some(&2));
+    }
+
+    #[test]
+    fn test_contains_position() {
+        let list: LinkedList<i32> = vec![5, 3, 7, 3].into_iter().collect();
+
+        assert!(list.contains(&7));
+        assert!(!list.contains(&4));
+
+        assert_eq!(list.position(&5), Some(0));
+        assert_eq!(list.position(&3), Some(1));
+        assert_eq!(list.position(&7), Some(2));
+        assert_eq!(list.position(&4), None);
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert!(!empty.contains(&1));
+        assert_eq!(empty.position(&1), None);
+    }
+
+    #[test]
+    fn test_eq() {
+        let a: LinkedList<i32> = (1..=3).collect();
+        let b: LinkedList<i32> = (1..=3).collect();
+        let longer: LinkedList<i32> = (1..=4).collect();
+        let different: LinkedList<i32> = vec![1, 2, 4].into_iter().collect();
+
+        assert_eq!(a, b);
+        assert_ne!(a, longer);
+        assert_ne!(a, different);
+        assert_eq!(LinkedList::<i32>::new(), LinkedList::new());
+    }
+
+    #[test]
+    fn test_from_array_and_slice() {
+        let list = LinkedList::from([1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+
+        let slice: &[i32] = &[4, 5];
+        let list = LinkedList::from(slice);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [4, 5]);
+
+        // Arrays of non-Clone elements are moved in
+        let list = LinkedList::from([Box::new(1), Box::new(2)]);
+        assert_eq!(list.back().map(|b| **b), Some(2));
+
+        let empty = LinkedList::<i32>::from([]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_clone_from() {
+        let source: LinkedList<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+
+        let mut shorter: LinkedList<String> = LinkedList::from([String::from("x")]);
+        shorter.clone_from(&source);
+        assert_eq!(shorter, source);
+
+        let mut longer: LinkedList<String> = (0..5).map(|i| i.to_string()).collect();
+        longer.clone_from(&source);
+        assert_eq!(longer, source);
+        assert_eq!(longer.back().map(String::as_str), Some("c"));
+        assert_eq!(longer.iter().rev().count(), 3);
+
+        let mut target = source.clone();
+        target.clone_from(&LinkedList::new());
+        assert!(target.is_empty());
+    }
+
+    #[test]
+    fn test_clone_from_reuses_nodes() {
+        let source: LinkedList<i32> = (0..100).collect();
+        let mut target: LinkedList<i32> = (0..100).rev().collect();
+        let head = target.head;
+        let tail = target.tail;
+
+        for _ in 0..1_000 {
+            target.clone_from(&source);
+        }
+
+        // The same nodes were overwritten in place every time
+        assert_eq!(target.head, head);
+        assert_eq!(target.tail, tail);
+        assert_eq!(target, source);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut list: LinkedList<String> = (1..=4).map(|i| i.to_string()).collect();
+        list.reverse();
+        assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec!["4", "3", "2", "1"]);
+        assert_eq!(list.iter().rev().cloned().collect::<Vec<_>>(), vec!["1", "2", "3", "4"]);
+        assert_eq!(list.front().map(String::as_str), Some("4"));
+        assert_eq!(list.back().map(String::as_str), Some("1"));
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        empty.reverse();
+        assert!(empty.is_empty());
+
+        let mut single: LinkedList<i32> = LinkedList::new();
+        single.push_back(1);
+        single.reverse();
+        assert_eq!(single.front(), Some(&1));
+        assert_eq!(single.back(), Some(&1));
+        assert_eq!(single.pop_back(), Some(1));
+    }
+
+    #[test]
+    fn test_sort() {
+        use rand::seq::SliceRandom;
+
+        let mut values: Vec<i32> = (0..1000).collect();
+        values.shuffle(&mut rand::thread_rng());
+
+        let mut list: LinkedList<i32> = values.into_iter().collect();
+        list.sort();
+
+        assert_eq!(list.len(), 1000);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), (0..1000).collect::<Vec<_>>());
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), (0..1000).rev().collect::<Vec<_>>());
+        assert_eq!(list.front(), Some(&0));
+        assert_eq!(list.back(), Some(&999));
+    }
+
+    #[test]
+    fn test_sort_by_is_stable() {
+        // Pairs of (key, insertion order)
+        let pairs = vec![(1, 0), (3, 1), (2, 2), (3, 3), (1, 4), (2, 5), (3, 6)];
+        let mut list: LinkedList<(i32, i32)> = pairs.into_iter().collect();
+
+        list.sort_by(|a, b| b.0.cmp(&a.0));
+
+        let sorted: Vec<_> = list.iter().copied().collect();
+        assert_eq!(sorted, vec![(3, 1), (3, 3), (3, 6), (2, 2), (2, 5), (1, 0), (1, 4)]);
+        assert_eq!(list.back(), Some(&(1, 4)));
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        empty.sort();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut list: LinkedList<i32> = vec![1, 3, 2, 4, 5, 7, 6, 9].into_iter().collect();
+        list.retain(|x| x % 2 == 0);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 4, 6]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![6, 4, 2]);
+        assert_eq!(list.front(), Some(&2));
+        assert_eq!(list.back(), Some(&6));
+    }
+
+    #[test]
+    fn test_drain_filter() {
+        let mut list: LinkedList<i32> = (0..10).collect();
+        let odds: Vec<i32> = list.drain_filter(|x| *x % 2 == 1).collect();
+
+        assert_eq!(odds, [1, 3, 5, 7, 9]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 2, 4, 6, 8]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [8, 6, 4, 2, 0]);
+        assert_eq!(list.back(), Some(&8));
+    }
+
+    #[test]
+    fn test_drain_filter_can_mutate() {
+        let mut list: LinkedList<i32> = (0..5).collect();
+        let drained: Vec<i32> = list
+            .drain_filter(|x| {
+                *x *= 10;
+                *x >= 30
+            })
+            .collect();
+
+        assert_eq!(drained, [30, 40]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 10, 20]);
+    }
+
+    #[test]
+    fn test_drain_filter_dropped_early() {
+        let mut list: LinkedList<i32> = (0..10).collect();
+        let mut drain = list.drain_filter(|x| *x % 2 == 1);
+        assert_eq!(drain.next(), Some(1));
+        drop(drain);
+
+        // The remaining odd elements were still removed
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 2, 4, 6, 8]);
+        assert_eq!(list.front(), Some(&0));
+        assert_eq!(list.back(), Some(&8));
+
+        // Removing the ends keeps the head and tail pointers consistent
+        let mut list: LinkedList<i32> = (0..3).collect();
+        list.drain_filter(|_| true);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn test_retain_none() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let mut list: LinkedList<Rc<()>> = (0..4).map(|_| Rc::clone(&tracker)).collect();
+        list.retain(|_| false);
+
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+
+        list.push_back(Rc::clone(&tracker));
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn test_index() {
+        let mut list: LinkedList<i32> = (0..5).collect();
+        assert_eq!(list[0], 0);
+        assert_eq!(list[2], 2);
+        assert_eq!(list[4], 4);
+
+        list[2] = 20;
+        list[3] += 10;
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 20, 13, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn test_index_out_of_bounds() {
+        let list: LinkedList<i32> = (0..3).collect();
+        let _ = list[3];
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+        list.rotate_left(2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [3, 4, 5, 1, 2]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [2, 1, 5, 4, 3]);
+
+        list.rotate_right(2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+
+        list.rotate_right(1);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [5, 1, 2, 3, 4]);
+        assert_eq!(list.front(), Some(&5));
+        assert_eq!(list.back(), Some(&4));
+
+        // Rotating by zero or by the full length is a no-op
+        list.rotate_left(0);
+        list.rotate_left(5);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [5, 1, 2, 3, 4]);
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "rotation amount (is 4) should be <= len (is 3)")]
+    fn test_rotate_out_of_bounds() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        list.rotate_right(4);
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        let mut list = LinkedList::from([1, 3, 5]);
+        list.merge_sorted(LinkedList::from([2, 4, 6]));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [6, 5, 4, 3, 2, 1]);
+        assert_eq!(list.len(), 6);
+        assert_eq!(list.back(), Some(&6));
+
+        // Runs of uneven length, where one is exhausted early
+        let mut list = LinkedList::from([5, 6, 7]);
+        list.merge_sorted(LinkedList::from([1, 2]));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 5, 6, 7]);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&7));
+    }
+
+    #[test]
+    fn test_merge_sorted_with_empty() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        list.merge_sorted(LinkedList::new());
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+        let mut list = LinkedList::new();
+        list.merge_sorted(LinkedList::from([1, 2, 3]));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn test_iter_exact_size_and_fused() {
+        let mut list = LinkedList::from([1, 2, 3]);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.by_ref().count(), 3);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_extend_from_list() {
+        let mut list = LinkedList::from(["a".to_string(), "b".to_string()]);
+        let other = LinkedList::from(["c".to_string(), "d".to_string()]);
+
+        list.extend_from_list(&other);
+        assert_eq!(list.iter().map(String::as_str).collect::<Vec<_>>(), ["a", "b", "c", "d"]);
+        assert_eq!(list.len(), 4);
+        assert_eq!(other.iter().map(String::as_str).collect::<Vec<_>>(), ["c", "d"]);
+        assert_eq!(other.len(), 2);
+
+        let mut empty = LinkedList::new();
+        empty.extend_from_list(&other);
+        assert_eq!(empty, other);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+        list.truncate(2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [2, 1]);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.back(), Some(&2));
+
+        list.push_back(6);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 6]);
+
+        // Longer than the list is a no-op
+        list.truncate(10);
+        assert_eq!(list.len(), 3);
+
+        list.truncate(0);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn test_split_at_value() {
+        let mut list = LinkedList::from([1, 2, 0, 3, 0, 4]);
+        let rest = list.split_at_value(&0).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(rest.iter().copied().collect::<Vec<_>>(), [0, 3, 0, 4]);
+        assert_eq!(list.back(), Some(&2));
+        assert_eq!(rest.len(), 4);
+
+        // A missing delimiter leaves the list untouched
+        assert!(list.split_at_value(&9).is_none());
+        assert_eq!(list.len(), 2);
+
+        // A delimiter at the head moves the whole list
+        let mut list = LinkedList::from([0, 1, 2]);
+        let rest = list.split_at_value(&0).unwrap();
+        assert!(list.is_empty());
+        assert_eq!(rest.iter().copied().collect::<Vec<_>>(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_iter_from() {
+        let list = LinkedList::from([1, 2, 3, 4, 5]);
+
+        let iter = list.iter_from(2);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.copied().collect::<Vec<_>>(), [3, 4, 5]);
+        assert_eq!(list.iter_from(2).rev().copied().collect::<Vec<_>>(), [5, 4, 3]);
+        assert_eq!(list.iter_from(0).count(), 5);
+
+        let mut empty = list.iter_from(5);
+        assert_eq!(empty.len(), 0);
+        assert_eq!(empty.next(), None);
+        assert_eq!(empty.next_back(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "start index (is 6) should be <= len (is 5)")]
+    fn test_iter_from_out_of_bounds() {
+        let list = LinkedList::from([1, 2, 3, 4, 5]);
+        list.iter_from(6);
+    }
+
+    #[test]
+    fn test_eq_vector() {
+        let list: LinkedList<i32> = (1..=4).collect();
+        let vec: Vector<i32> = (1..=4).collect();
+        assert!(list == vec);
+        assert!(vec == list);
+
+        let shorter: Vector<i32> = (1..=3).collect();
+        assert!(list != shorter);
+        assert!(shorter != list);
+
+        let different = Vector::from([1, 2, 3, 5]);
+        assert!(list != different);
+        assert!(different != list);
+
+        assert!(LinkedList::<i32>::new() == Vector::<i32>::new());
+    }
+
+    #[test]
+    fn test_map() {
+        let list = LinkedList::from([1, 2, 3]);
+        let strings: LinkedList<String> = list.map(|x| x.to_string());
+
+        assert_eq!(strings.len(), 3);
+        assert_eq!(strings.iter().map(String::as_str).collect::<Vec<_>>(), ["1", "2", "3"]);
+        assert_eq!(strings.back().map(String::as_str), Some("3"));
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn test_find_map() {
+        let list = LinkedList::from([1, 4, 7, 10]);
+        let mut calls = 0;
+        let found = list.find_map(|&x| {
+            calls += 1;
+            (x % 2 == 0).then(|| x * 100)
+        });
+        assert_eq!(found, Some(400));
+        assert_eq!(calls, 2);
+
+        assert_eq!(list.find_map(|&x| (x > 10).then_some(x)), None);
+        assert_eq!(LinkedList::<i32>::new().find_map(|&x| Some(x)), None);
+    }
+
+    #[test]
+    fn test_into_vector() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let list: LinkedList<(i32, Rc<()>)> = (1..=5).map(|i| (i, Rc::clone(&tracker))).collect();
+        let vec = list.into_vector();
+
+        assert_eq!(vec.len(), 5);
+        assert_eq!(vec.capacity(), 5);
+        assert_eq!(vec.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+        // Every element was moved, none cloned or dropped
+        assert_eq!(Rc::strong_count(&tracker), 6);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+
+        assert!(LinkedList::<i32>::new().into_vector().is_empty());
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut list = LinkedList::from([1, 2, 3, 4]);
+        list.swap(0, 3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [4, 2, 3, 1]);
+        assert_eq!(list.front(), Some(&4));
+        assert_eq!(list.back(), Some(&1));
+
+        list.swap(2, 1);
+        list.swap(2, 2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [4, 3, 2, 1]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 4 but the index is 4")]
+    fn test_swap_out_of_bounds() {
+        LinkedList::from([1, 2, 3, 4]).swap(0, 4);
+    }
+
+    #[test]
+    fn test_clear_drops_each_once() {
+        use std::cell::Cell;
+
+        struct Counted<'a>(&'a Cell<usize>);
+
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let mut list: LinkedList<Counted> = (0..10_000).map(|_| Counted(&drops)).collect();
+        list.clear();
+        assert_eq!(drops.get(), 10_000);
+        assert!(list.is_empty());
+        assert!(list.front().is_none() && list.back().is_none());
+
+        // The list is still usable, and dropping it later does not touch the
+        // freed nodes
+        list.push_back(Counted(&drops));
+        assert_eq!(list.len(), 1);
+        drop(list);
+        assert_eq!(drops.get(), 10_001);
+    }
+
+    #[test]
+    fn test_clear_panicking_drop() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        struct PanicOnDrop {
+            _tracker: Rc<()>,
+            panics: bool,
+        }
+
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                if self.panics {
+                    panic!("drop");
+                }
+            }
+        }
+
+        let tracker = Rc::new(());
+        let mut list: LinkedList<PanicOnDrop> = (0..5)
+            .map(|i| PanicOnDrop {
+                _tracker: Rc::clone(&tracker),
+                panics: i == 1,
+            })
+            .collect();
+        assert!(catch_unwind(AssertUnwindSafe(|| list.clear())).is_err());
+
+        // Every node was freed despite the panic
+        assert_eq!(Rc::strong_count(&tracker), 1);
+        assert!(list.is_empty());
+    }
+}
@@ -0,0 +1,335 @@
+//! A linked list that rejects duplicate elements
+//!
+//! This module provides a wrapper over [`LinkedList`] that keeps an auxiliary
+//! hash index in sync with the list so membership checks are O(1).
+
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::ptr::NonNull;
+use std::collections::hash_map::{Entry, RandomState};
+use std::collections::HashMap;
+
+use super::{Iter, LinkedList, Node};
+
+/// A doubly linked list that holds each element at most once
+///
+/// Insertions of an element that is already present are rejected, which
+/// makes this suitable for deduplicating work queues. Elements are only
+/// stored once, in the list; the index refers to them through their nodes,
+/// so they do not need to implement `Clone`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::linked_list::UniqueList;
+///
+/// let mut list = UniqueList::new();
+/// assert!(list.push_back(1));
+/// assert!(list.push_back(2));
+/// assert!(!list.push_front(1));
+///
+/// assert_eq!(list.len(), 2);
+/// assert_eq!(list.pop_front(), Some(1));
+/// assert!(list.push_back(1));
+/// ```
+pub struct UniqueList<T> {
+    list: LinkedList<T>,
+    // The nodes of `list`, keyed by the hash of their element. Elements are
+    // compared through the nodes, so they never have to be cloned; a bucket
+    // only holds more than one node on a hash collision.
+    index: HashMap<u64, Vec<NonNull<Node<T>>>>,
+    hasher: RandomState,
+}
+
+// The index only points into nodes owned by `list`
+unsafe impl<T: Send> Send for UniqueList<T> {}
+unsafe impl<T: Sync> Sync for UniqueList<T> {}
+
+impl<T: Hash + Eq> Default for UniqueList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + Eq> UniqueList<T> {
+    /// Creates a new, empty unique list
+    pub fn new() -> Self {
+        UniqueList {
+            list: LinkedList::new(),
+            index: HashMap::new(),
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// Returns the length of the list
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns whether the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns whether the list contains the given element
+    pub fn contains(&self, element: &T) -> bool {
+        self.find(self.hasher.hash_one(element), element).is_some()
+    }
+
+    /// Adds an element to the front of the list
+    ///
+    /// Returns `false` and leaves the list unchanged if the element is
+    /// already present.
+    pub fn push_front(&mut self, element: T) -> bool {
+        let hash = self.hasher.hash_one(&element);
+        if self.find(hash, &element).is_some() {
+            return false;
+        }
+        self.list.push_front(element);
+        self.track(hash, self.list.head);
+        true
+    }
+
+    /// Adds an element to the back of the list
+    ///
+    /// Returns `false` and leaves the list unchanged if the element is
+    /// already present.
+    pub fn push_back(&mut self, element: T) -> bool {
+        let hash = self.hasher.hash_one(&element);
+        if self.find(hash, &element).is_some() {
+            return false;
+        }
+        self.list.push_back(element);
+        self.track(hash, self.list.tail);
+        true
+    }
+
+    /// Removes the front element from the list and returns it
+    pub fn pop_front(&mut self) -> Option<T> {
+        let node = self.list.head?;
+        Some(self.unlink(node))
+    }
+
+    /// Removes the back element from the list and returns it
+    pub fn pop_back(&mut self) -> Option<T> {
+        let node = self.list.tail?;
+        Some(self.unlink(node))
+    }
+
+    /// Removes the given element from the list, returning whether it was present
+    pub fn remove(&mut self, element: &T) -> bool {
+        match self.find(self.hasher.hash_one(element), element) {
+            Some(node) => {
+                self.unlink(node);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns a reference to the front element
+    pub fn front(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    /// Returns a reference to the back element
+    pub fn back(&self) -> Option<&T> {
+        self.list.back()
+    }
+
+    /// Returns an iterator over the list
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    /// Resets the list, removing all elements and forgetting every element seen
+    pub fn reset(&mut self) {
+        self.index.clear();
+        self.list.clear();
+    }
+
+    // Private method to find the node holding an element equal to `element`
+    fn find(&self, hash: u64, element: &T) -> Option<NonNull<Node<T>>> {
+        let bucket = self.index.get(&hash)?;
+        bucket
+            .iter()
+            .copied()
+            .find(|node| unsafe { (*node.as_ptr()).element == *element })
+    }
+
+    // Private method to index a node that was just pushed onto the list
+    fn track(&mut self, hash: u64, node: Option<NonNull<Node<T>>>) {
+        let node = node.expect("a node was just pushed");
+        self.index.entry(hash).or_default().push(node);
+    }
+
+    // Private method to drop a node from the index and unlink it
+    //
+    // The index must never keep a pointer to a freed node. If the element's
+    // hash changed while it was in the list, which is a logic error in its
+    // `Hash` or `Eq` impl, the node is not in its expected bucket and every
+    // bucket is searched instead.
+    fn unlink(&mut self, node: NonNull<Node<T>>) -> T {
+        let hash = self.hasher.hash_one(unsafe { &(*node.as_ptr()).element });
+        let found = match self.index.entry(hash) {
+            Entry::Occupied(mut bucket) => {
+                let before = bucket.get().len();
+                bucket.get_mut().retain(|&tracked| tracked != node);
+                let found = bucket.get().len() < before;
+                if bucket.get().is_empty() {
+                    bucket.remove();
+                }
+                found
+            }
+            Entry::Vacant(_) => false,
+        };
+        if !found {
+            self.index.retain(|_, bucket| {
+                bucket.retain(|&tracked| tracked != node);
+                !bucket.is_empty()
+            });
+        }
+
+        unsafe { self.list.unlink_node(node) }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for UniqueList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.list.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_consistent<T: Hash + Eq>(list: &UniqueList<T>) {
+        assert_eq!(list.list.len(), list.index.values().map(Vec::len).sum::<usize>());
+        for item in list.iter() {
+            assert!(list.contains(item));
+        }
+    }
+
+    #[test]
+    fn test_push_rejects_duplicates() {
+        let mut list = UniqueList::new();
+        assert!(list.push_back(1));
+        assert!(list.push_back(2));
+        assert!(list.push_front(3));
+
+        assert!(!list.push_back(1));
+        assert!(!list.push_front(2));
+        assert!(!list.push_back(3));
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 1, 2]);
+        assert_consistent(&list);
+    }
+
+    #[test]
+    fn test_remove_then_push() {
+        let mut list = UniqueList::new();
+        list.push_back("a");
+        list.push_back("b");
+        list.push_back("c");
+
+        assert!(list.remove(&"b"));
+        assert!(!list.remove(&"b"));
+        assert!(!list.contains(&"b"));
+        assert_consistent(&list);
+
+        assert!(list.push_back("b"));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec!["a", "c", "b"]);
+
+        assert_eq!(list.pop_front(), Some("a"));
+        assert_eq!(list.pop_back(), Some("b"));
+        assert!(list.push_front("a"));
+        assert!(list.push_back("b"));
+        assert_consistent(&list);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut list = UniqueList::new();
+        list.push_back(1);
+        list.push_back(2);
+
+        list.reset();
+        assert!(list.is_empty());
+        assert!(!list.contains(&1));
+        assert!(list.push_back(1));
+        assert_consistent(&list);
+    }
+
+    #[test]
+    fn test_non_clone_elements() {
+        #[derive(Debug, PartialEq, Eq, Hash)]
+        struct Job(u32);
+
+        let mut list = UniqueList::new();
+        assert!(list.push_back(Job(1)));
+        assert!(list.push_back(Job(2)));
+        assert!(!list.push_front(Job(1)));
+        assert!(list.contains(&Job(2)));
+
+        assert!(list.remove(&Job(1)));
+        assert_eq!(list.pop_back(), Some(Job(2)));
+        assert!(list.is_empty());
+        assert_consistent(&list);
+    }
+
+    #[test]
+    fn test_hash_collisions() {
+        // Every value hashes the same, so all nodes share one bucket
+        #[derive(Debug, PartialEq, Eq)]
+        struct Colliding(u32);
+
+        impl Hash for Colliding {
+            fn hash<H: core::hash::Hasher>(&self, _: &mut H) {}
+        }
+
+        let mut list = UniqueList::new();
+        for i in 0..5 {
+            assert!(list.push_back(Colliding(i)));
+        }
+        assert!(!list.push_back(Colliding(3)));
+        assert_eq!(list.index.len(), 1);
+
+        assert!(list.remove(&Colliding(2)));
+        assert!(!list.contains(&Colliding(2)));
+        assert_eq!(list.pop_front(), Some(Colliding(0)));
+        assert_eq!(list.iter().map(|c| c.0).collect::<Vec<_>>(), [1, 3, 4]);
+        assert_consistent(&list);
+    }
+
+    #[test]
+    fn test_mutated_hash_is_handled() {
+        use std::cell::Cell;
+
+        // Hashing through a `Cell` lets an element's hash change while it
+        // is in the list, which is a logic error but must not be unsound
+        #[derive(Debug, PartialEq, Eq)]
+        struct Key(Cell<u32>);
+
+        impl Hash for Key {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.0.get().hash(state);
+            }
+        }
+
+        let mut list = UniqueList::new();
+        list.push_back(Key(Cell::new(1)));
+        list.push_back(Key(Cell::new(2)));
+        list.front().unwrap().0.set(10);
+
+        assert!(!list.remove(&Key(Cell::new(1))));
+        assert_eq!(list.len(), 2);
+
+        // Popping the mutated element still clears it from the index
+        assert_eq!(list.pop_front(), Some(Key(Cell::new(10))));
+        assert_eq!(list.len(), 1);
+        assert_consistent(&list);
+        assert!(list.push_back(Key(Cell::new(10))));
+    }
+}
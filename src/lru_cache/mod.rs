@@ -0,0 +1,255 @@
+//! A least recently used (LRU) cache implementation
+//!
+//! This module provides a fixed-capacity cache that evicts the least recently
+//! used entry when it overflows. Entries live in a [`LinkedList`] ordered by
+//! recency, and a hash map points straight at their nodes so that lookups,
+//! insertions and recency updates are all O(1).
+
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ptr::NonNull;
+use std::collections::HashMap;
+
+use crate::linked_list::{LinkedList, Node};
+
+// A pointer to a key stored inside a list node
+//
+// Keys are owned by the list, so the map only needs to borrow them. This
+// avoids requiring `K: Clone`.
+struct KeyRef<K> {
+    key: *const K,
+}
+
+impl<K: Hash> Hash for KeyRef<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        unsafe { (*self.key).hash(state) }
+    }
+}
+
+impl<K: PartialEq> PartialEq for KeyRef<K> {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { *self.key == *other.key }
+    }
+}
+
+impl<K: Eq> Eq for KeyRef<K> {}
+
+impl<K> Borrow<K> for KeyRef<K> {
+    fn borrow(&self) -> &K {
+        unsafe { &*self.key }
+    }
+}
+
+/// A fixed-capacity cache that evicts the least recently used entry
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::lru_cache::LruCache;
+///
+/// let mut cache = LruCache::new(2);
+/// cache.put("a", 1);
+/// cache.put("b", 2);
+///
+/// // Reading "a" makes "b" the least recently used entry
+/// assert_eq!(cache.get(&"a"), Some(&1));
+/// cache.put("c", 3);
+///
+/// assert_eq!(cache.get(&"b"), None);
+/// assert_eq!(cache.get(&"a"), Some(&1));
+/// assert_eq!(cache.get(&"c"), Some(&3));
+/// ```
+pub struct LruCache<K: Hash + Eq, V> {
+    map: HashMap<KeyRef<K>, NonNull<Node<(K, V)>>>,
+    // Most recently used entries are at the front
+    list: LinkedList<(K, V)>,
+    capacity: usize,
+}
+
+// Safe to implement Send and Sync if K and V are Send and Sync
+unsafe impl<K: Hash + Eq + Send, V: Send> Send for LruCache<K, V> {}
+unsafe impl<K: Hash + Eq + Sync, V: Sync> Sync for LruCache<K, V> {}
+
+impl<K: Hash + Eq, V> LruCache<K, V> {
+    /// Creates a new, empty cache holding at most `capacity` entries
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "LRU cache capacity must be non-zero");
+
+        LruCache {
+            map: HashMap::with_capacity(capacity),
+            list: LinkedList::new(),
+            capacity,
+        }
+    }
+
+    /// Returns the number of entries in the cache
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns the maximum number of entries the cache can hold
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns whether the cache contains `key`, without updating its recency
+    pub fn contains(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// Returns a reference to the value for `key` and marks it as most
+    /// recently used
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let node = *self.map.get(key)?;
+        unsafe {
+            self.list.move_node_to_front(node);
+            Some(&(*node.as_ptr()).element.1)
+        }
+    }
+
+    /// Returns a mutable reference to the value for `key` and marks it as
+    /// most recently used
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let node = *self.map.get(key)?;
+        unsafe {
+            self.list.move_node_to_front(node);
+            Some(&mut (*node.as_ptr()).element.1)
+        }
+    }
+
+    /// Returns a reference to the value for `key` without updating its recency
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        let node = *self.map.get(key)?;
+        unsafe { Some(&(*node.as_ptr()).element.1) }
+    }
+
+    /// Inserts a key-value pair and marks it as most recently used
+    ///
+    /// If the key was already present its value is replaced and the old value
+    /// is returned. Otherwise, if the cache is full, the least recently used
+    /// entry is evicted first.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&node) = self.map.get(&key) {
+            unsafe {
+                self.list.move_node_to_front(node);
+                return Some(core::mem::replace(&mut (*node.as_ptr()).element.1, value));
+            }
+        }
+
+        if self.list.len() == self.capacity {
+            self.pop_lru();
+        }
+
+        let node = self.list.push_front_node((key, value));
+        let key = unsafe { &(*node.as_ptr()).element.0 as *const K };
+        self.map.insert(KeyRef { key }, node);
+        None
+    }
+
+    /// Removes the entry for `key` and returns its value
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let node = self.map.remove(key)?;
+        unsafe { Some(self.list.remove_node(node).1) }
+    }
+
+    /// Removes the least recently used entry and returns it
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let node = self.list.back_node()?;
+        unsafe {
+            self.map.remove(&(*node.as_ptr()).element.0);
+            Some(self.list.remove_node(node))
+        }
+    }
+
+    /// Removes all entries from the cache
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.list.clear();
+    }
+}
+
+impl<K: Hash + Eq + fmt::Debug, V: fmt::Debug> fmt::Debug for LruCache<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.list.iter().map(|(key, value)| (key, value)))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eviction_order() {
+        let mut cache = LruCache::new(3);
+        assert_eq!(cache.put(1, "one"), None);
+        assert_eq!(cache.put(2, "two"), None);
+        assert_eq!(cache.put(3, "three"), None);
+        assert_eq!(cache.len(), 3);
+
+        cache.put(4, "four");
+        assert_eq!(cache.len(), 3);
+        assert!(!cache.contains(&1));
+
+        cache.put(5, "five");
+        assert!(!cache.contains(&2));
+        assert_eq!(cache.peek(&3), Some(&"three"));
+        assert_eq!(cache.peek(&4), Some(&"four"));
+        assert_eq!(cache.peek(&5), Some(&"five"));
+
+        assert_eq!(cache.pop_lru(), Some((3, "three")));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_get_updates_recency() {
+        let mut cache = LruCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+
+        assert_eq!(cache.get(&"a"), Some(&1));
+        cache.put("c", 3);
+        assert_eq!(cache.get(&"b"), None);
+        assert_eq!(cache.get(&"a"), Some(&1));
+
+        // peek does not bump recency, so "c" stays least recently used
+        assert_eq!(cache.peek(&"c"), Some(&3));
+        cache.put("d", 4);
+        assert!(!cache.contains(&"c"));
+        assert!(cache.contains(&"a"));
+    }
+
+    #[test]
+    fn test_put_existing_key() {
+        let mut cache = LruCache::new(2);
+        cache.put(String::from("a"), 1);
+        cache.put(String::from("b"), 2);
+
+        assert_eq!(cache.put(String::from("a"), 10), Some(1));
+        assert_eq!(cache.len(), 2);
+
+        // Updating "a" made it most recently used
+        cache.put(String::from("c"), 3);
+        assert_eq!(cache.peek(&String::from("a")), Some(&10));
+        assert!(!cache.contains(&String::from("b")));
+
+        *cache.get_mut(&String::from("c")).unwrap() += 1;
+        assert_eq!(cache.remove(&String::from("c")), Some(4));
+        assert_eq!(cache.remove(&String::from("c")), None);
+        assert_eq!(cache.len(), 1);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,325 @@
+//! A slot map with generational keys
+//!
+//! This module provides a map that assigns its own keys. Values live in a
+//! single [`Vector`] of slots, and a key is a slot index paired with the
+//! slot's generation. Removing a value bumps the generation, so keys to a
+//! removed value stop resolving even after the slot has been reused.
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::vector::Vector;
+
+/// A handle to a value in a [`SlotMap`]
+///
+/// Keys are cheap to copy and stay valid until their value is removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key {
+    index: usize,
+    generation: u32,
+}
+
+struct Slot<V> {
+    generation: u32,
+    state: SlotState<V>,
+}
+
+enum SlotState<V> {
+    Occupied(V),
+    // Free slots form a linked list through the vector, newest first
+    Free { next: Option<usize> },
+}
+
+/// A map that hands out stable, generational keys for inserted values
+///
+/// Insertion, lookup and removal are all O(1). Freed slots are reused by
+/// later insertions, so memory use is bounded by the peak number of live
+/// values, apart from slots retired after 2^32 reuses.
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::slotmap::SlotMap;
+///
+/// let mut map = SlotMap::new();
+/// let a = map.insert("a");
+/// let b = map.insert("b");
+///
+/// assert_eq!(map.remove(a), Some("a"));
+/// assert_eq!(map.get(a), None);
+/// assert_eq!(map.get(b), Some(&"b"));
+/// ```
+pub struct SlotMap<V> {
+    slots: Vector<Slot<V>>,
+    free_head: Option<usize>,
+    len: usize,
+}
+
+impl<V> Default for SlotMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> SlotMap<V> {
+    /// Creates a new, empty slot map
+    pub fn new() -> Self {
+        SlotMap {
+            slots: Vector::new(),
+            free_head: None,
+            len: 0,
+        }
+    }
+
+    /// Creates a new, empty slot map with room for `capacity` values
+    pub fn with_capacity(capacity: usize) -> Self {
+        SlotMap {
+            slots: Vector::with_capacity(capacity),
+            free_head: None,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of values in the map
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the map is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts a value and returns the key that refers to it
+    ///
+    /// A freed slot is reused if there is one; its key carries the bumped
+    /// generation, so it never equals a key handed out before.
+    pub fn insert(&mut self, value: V) -> Key {
+        self.len += 1;
+
+        match self.free_head {
+            Some(index) => {
+                let slot = &mut self.slots[index];
+                if let SlotState::Free { next } = slot.state {
+                    self.free_head = next;
+                }
+                slot.state = SlotState::Occupied(value);
+                Key {
+                    index,
+                    generation: slot.generation,
+                }
+            }
+            None => {
+                let index = self.slots.len();
+                self.slots.push(Slot {
+                    generation: 0,
+                    state: SlotState::Occupied(value),
+                });
+                Key { index, generation: 0 }
+            }
+        }
+    }
+
+    /// Returns a reference to the value for `key`, or `None` if it has been
+    /// removed
+    pub fn get(&self, key: Key) -> Option<&V> {
+        match self.slots.get(key.index) {
+            Some(Slot {
+                generation,
+                state: SlotState::Occupied(value),
+            }) if *generation == key.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value for `key`, or `None` if it
+    /// has been removed
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut V> {
+        match self.slots.get_mut(key.index) {
+            Some(Slot {
+                generation,
+                state: SlotState::Occupied(value),
+            }) if *generation == key.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns whether `key` refers to a value in the map
+    pub fn contains_key(&self, key: Key) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Removes the value for `key` and returns it
+    ///
+    /// The slot is freed for reuse with a bumped generation, so `key` and
+    /// any copies of it no longer resolve. A slot whose generation would
+    /// wrap around is retired instead of reused, so stale keys can never
+    /// resolve again. Returns `None` if the key was already stale.
+    pub fn remove(&mut self, key: Key) -> Option<V> {
+        if !self.contains_key(key) {
+            return None;
+        }
+
+        let slot = &mut self.slots[key.index];
+        let state = match slot.generation.checked_add(1) {
+            Some(generation) => {
+                slot.generation = generation;
+                let state = core::mem::replace(&mut slot.state, SlotState::Free { next: self.free_head });
+                self.free_head = Some(key.index);
+                state
+            }
+            // Left off the free list for good; being free, it matches no key
+            None => core::mem::replace(&mut slot.state, SlotState::Free { next: None }),
+        };
+        self.len -= 1;
+
+        match state {
+            SlotState::Occupied(value) => Some(value),
+            SlotState::Free { .. } => unreachable!(),
+        }
+    }
+
+    /// Returns an iterator over the keys and values, in slot order
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter {
+            slots: self.slots.iter().enumerate(),
+            remaining: self.len,
+        }
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for SlotMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a SlotMap<V> {
+    type Item = (Key, &'a V);
+    type IntoIter = Iter<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the keys and values of a [`SlotMap`]
+pub struct Iter<'a, V> {
+    slots: core::iter::Enumerate<core::slice::Iter<'a, Slot<V>>>,
+    remaining: usize,
+}
+
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (Key, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, slot) in self.slots.by_ref() {
+            if let SlotState::Occupied(value) = &slot.state {
+                self.remaining -= 1;
+                let key = Key {
+                    index,
+                    generation: slot.generation,
+                };
+                return Some((key, value));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<V> ExactSizeIterator for Iter<'_, V> {}
+
+impl<V> FusedIterator for Iter<'_, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_get_remove() {
+        let mut map = SlotMap::new();
+        let a = map.insert(1);
+        let b = map.insert(2);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(a), Some(&1));
+
+        *map.get_mut(b).unwrap() += 10;
+        assert_eq!(map.get(b), Some(&12));
+
+        assert_eq!(map.remove(a), Some(1));
+        assert_eq!(map.remove(a), None);
+        assert_eq!(map.get(a), None);
+        assert!(!map.contains_key(a));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_reused_slot_rejects_stale_key() {
+        let mut map = SlotMap::new();
+        let old = map.insert("old");
+        map.remove(old);
+
+        // The freed slot is reused, but with a new generation
+        let new = map.insert("new");
+        assert_ne!(old, new);
+        assert_eq!(map.get(new), Some(&"new"));
+        assert_eq!(map.get(old), None);
+        assert_eq!(map.get_mut(old), None);
+        assert_eq!(map.remove(old), None);
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.slots.len(), 1);
+    }
+
+    #[test]
+    fn test_free_list_and_iter() {
+        let mut map = SlotMap::new();
+        let keys: Vec<Key> = (0..5).map(|i| map.insert(i)).collect();
+        map.remove(keys[1]);
+        map.remove(keys[3]);
+
+        let values: Vec<i32> = map.iter().map(|(_, &v)| v).collect();
+        assert_eq!(values, [0, 2, 4]);
+        assert_eq!(map.iter().len(), 3);
+        for (key, value) in &map {
+            assert_eq!(map.get(key), Some(value));
+        }
+
+        // Both freed slots are reused before the vector grows
+        map.insert(10);
+        map.insert(11);
+        assert_eq!(map.slots.len(), 5);
+        map.insert(12);
+        assert_eq!(map.slots.len(), 6);
+        assert_eq!(map.len(), 6);
+    }
+
+    #[test]
+    fn test_slot_retired_before_generation_wraps() {
+        let mut map = SlotMap::new();
+        let key = map.insert("a");
+        map.slots[key.index].generation = u32::MAX - 1;
+        let key = Key {
+            index: key.index,
+            generation: u32::MAX - 1,
+        };
+
+        // One more reuse reaches the last generation
+        map.remove(key);
+        let last = map.insert("b");
+        assert_eq!(last.index, key.index);
+        assert_eq!(last.generation, u32::MAX);
+
+        // Removing it again retires the slot rather than wrapping to 0
+        assert_eq!(map.remove(last), Some("b"));
+        let fresh = map.insert("c");
+        assert_ne!(fresh.index, key.index);
+        assert_eq!(map.slots.len(), 2);
+        assert_eq!(map.get(last), None);
+        assert_eq!(map.get(Key { index: key.index, generation: 0 }), None);
+        assert_eq!(map.len(), 1);
+    }
+}
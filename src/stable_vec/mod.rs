@@ -0,0 +1,229 @@
+//! A vector with stable indices
+//!
+//! This module provides a vector where removing an element leaves a hole
+//! instead of shifting the elements after it, so every element keeps its
+//! index for as long as it lives. Holes are filled again by later pushes,
+//! lowest index first.
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::binary_heap::{BinaryHeap, MinComparator};
+use crate::vector::Vector;
+
+/// A vector whose elements keep their index across removals
+///
+/// Slots are stored as `Option<T>` in the crate's [`Vector`]; the free
+/// slots are kept in a min-heap so that [`push`](StableVec::push) always
+/// reuses the lowest one in O(log n).
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::stable_vec::StableVec;
+///
+/// let mut vec = StableVec::new();
+/// let a = vec.push("a");
+/// let b = vec.push("b");
+/// let c = vec.push("c");
+///
+/// vec.remove(b);
+/// assert_eq!(vec.get(a), Some(&"a"));
+/// assert_eq!(vec.get(b), None);
+/// assert_eq!(vec.get(c), Some(&"c"));
+///
+/// // The hole is reused
+/// assert_eq!(vec.push("d"), b);
+/// ```
+pub struct StableVec<T> {
+    slots: Vector<Option<T>>,
+    holes: BinaryHeap<usize, MinComparator>,
+    len: usize,
+}
+
+impl<T> Default for StableVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> StableVec<T> {
+    /// Creates a new, empty stable vector
+    pub fn new() -> Self {
+        StableVec {
+            slots: Vector::new(),
+            holes: BinaryHeap::new_min(),
+            len: 0,
+        }
+    }
+
+    /// Creates a new, empty stable vector with room for `capacity` elements
+    pub fn with_capacity(capacity: usize) -> Self {
+        StableVec {
+            slots: Vector::with_capacity(capacity),
+            holes: BinaryHeap::new_min(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements, not counting holes
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether there are no elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of slots, including holes
+    ///
+    /// Every index handed out so far is below this.
+    pub fn num_slots(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Adds an element and returns its index
+    ///
+    /// The lowest hole is filled if there is one; otherwise the element is
+    /// appended after the last slot.
+    pub fn push(&mut self, value: T) -> usize {
+        self.len += 1;
+
+        match self.holes.pop() {
+            Some(index) => {
+                self.slots[index] = Some(value);
+                index
+            }
+            None => {
+                self.slots.push(Some(value));
+                self.slots.len() - 1
+            }
+        }
+    }
+
+    /// Removes the element at `index` and returns it, leaving a hole
+    ///
+    /// No other element moves. Returns `None` if `index` is already a hole
+    /// or out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let value = self.slots.get_mut(index)?.take()?;
+        self.holes.push(index);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Returns a reference to the element at `index`, or `None` for a hole
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.slots.get(index)?.as_ref()
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` for
+    /// a hole
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.slots.get_mut(index)?.as_mut()
+    }
+
+    /// Returns an iterator over the indices and elements in index order,
+    /// skipping holes
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            slots: self.slots.iter().enumerate(),
+            remaining: self.len,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for StableVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for StableVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = StableVec::new();
+        for value in iter {
+            vec.push(value);
+        }
+        vec
+    }
+}
+
+impl<'a, T> IntoIterator for &'a StableVec<T> {
+    type Item = (usize, &'a T);
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`StableVec`] and their indices
+pub struct Iter<'a, T> {
+    slots: core::iter::Enumerate<core::slice::Iter<'a, Option<T>>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, slot) in self.slots.by_ref() {
+            if let Some(value) = slot {
+                self.remaining -= 1;
+                return Some((index, value));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indices_stable_across_removals() {
+        let mut vec: StableVec<i32> = (0..6).map(|i| i * 10).collect();
+        assert_eq!(vec.remove(1), Some(10));
+        assert_eq!(vec.remove(4), Some(40));
+        assert_eq!(vec.remove(4), None);
+        assert_eq!(vec.remove(100), None);
+
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec.num_slots(), 6);
+        assert_eq!(vec.get(0), Some(&0));
+        assert_eq!(vec.get(1), None);
+        assert_eq!(vec.get(5), Some(&50));
+
+        *vec.get_mut(3).unwrap() += 1;
+        assert_eq!(vec.iter().collect::<Vec<_>>(), [(0, &0), (2, &20), (3, &31), (5, &50)]);
+        assert_eq!(vec.iter().len(), 4);
+        assert_eq!(format!("{:?}", vec), "{0: 0, 2: 20, 3: 31, 5: 50}");
+    }
+
+    #[test]
+    fn test_push_reuses_lowest_hole() {
+        let mut vec: StableVec<&str> = ["a", "b", "c", "d"].into_iter().collect();
+        vec.remove(3);
+        vec.remove(1);
+        vec.remove(2);
+
+        assert_eq!(vec.push("x"), 1);
+        assert_eq!(vec.push("y"), 2);
+        assert_eq!(vec.push("z"), 3);
+        assert_eq!(vec.push("w"), 4);
+        assert_eq!(vec.get(0), Some(&"a"));
+        assert_eq!(vec.len(), 5);
+        assert_eq!(vec.num_slots(), 5);
+    }
+}
@@ -0,0 +1,348 @@
+//! A trie (prefix tree) implementation
+//!
+//! This module provides a map keyed by strings, where keys sharing a prefix
+//! share the nodes for that prefix. Each node keeps its children in a map
+//! keyed by the next character.
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+// A node in the trie
+//
+// A node holds a value only if a key ends exactly at it. Children are kept in
+// an ordered map so that traversal visits keys in lexicographic order.
+struct TrieNode<V> {
+    value: Option<V>,
+    children: BTreeMap<char, TrieNode<V>>,
+}
+
+impl<V> TrieNode<V> {
+    fn new() -> Self {
+        TrieNode {
+            value: None,
+            children: BTreeMap::new(),
+        }
+    }
+
+    // A node can be pruned once it holds no value and has no children
+    fn is_prunable(&self) -> bool {
+        self.value.is_none() && self.children.is_empty()
+    }
+}
+
+/// A map from string keys to values, organised as a prefix tree
+///
+/// Lookups, insertions and removals take time proportional to the length of
+/// the key, independent of how many keys are stored.
+///
+/// # Examples
+///
+/// ```
+/// use rust_data_structures::trie::Trie;
+///
+/// let mut trie = Trie::new();
+/// trie.insert("car", 1);
+/// trie.insert("cart", 2);
+///
+/// assert_eq!(trie.get("car"), Some(&1));
+/// assert_eq!(trie.get("cart"), Some(&2));
+/// assert_eq!(trie.get("ca"), None);
+/// ```
+pub struct Trie<V> {
+    root: TrieNode<V>,
+    len: usize,
+}
+
+impl<V> Trie<V> {
+    /// Creates a new, empty trie
+    pub fn new() -> Self {
+        Trie {
+            root: TrieNode::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of keys in the trie
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the trie is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts a key-value pair into the trie
+    ///
+    /// If the key was already present, its value is replaced and the old
+    /// value is returned.
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        let mut node = &mut self.root;
+        for c in key.chars() {
+            node = node.children.entry(c).or_insert_with(TrieNode::new);
+        }
+
+        let old = node.value.replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Returns a reference to the value for `key`
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.find_node(key)?.value.as_ref()
+    }
+
+    /// Returns a mutable reference to the value for `key`
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        let mut node = &mut self.root;
+        for c in key.chars() {
+            node = node.children.get_mut(&c)?;
+        }
+        node.value.as_mut()
+    }
+
+    /// Returns whether the trie contains `key`
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns whether any stored key begins with `prefix`
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        // Pruning on removal guarantees every existing node leads to a key
+        self.find_node(prefix).is_some_and(|node| !node.is_prunable())
+    }
+
+    /// Returns an iterator over every stored key beginning with `prefix`,
+    /// in sorted order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::trie::Trie;
+    ///
+    /// let mut trie = Trie::new();
+    /// trie.insert("car", 1);
+    /// trie.insert("cart", 2);
+    /// trie.insert("dog", 3);
+    ///
+    /// let completions: Vec<String> = trie.keys_with_prefix("ca").collect();
+    /// assert_eq!(completions, ["car", "cart"]);
+    /// ```
+    pub fn keys_with_prefix<'a>(&'a self, prefix: &str) -> impl Iterator<Item = String> + 'a {
+        let mut stack = Vec::new();
+        if let Some(node) = self.find_node(prefix) {
+            stack.push((prefix.to_string(), node));
+        }
+        KeysWithPrefix { stack }
+    }
+
+    /// Removes `key` from the trie, returning its value if it was present
+    ///
+    /// Nodes left without a value or children are pruned, so other keys
+    /// sharing a prefix with `key` are unaffected.
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        let chars: Vec<char> = key.chars().collect();
+        let removed = Self::remove_from(&mut self.root, &chars)?;
+        self.len -= 1;
+        Some(removed)
+    }
+
+    /// Removes every key from the trie
+    pub fn clear(&mut self) {
+        self.root = TrieNode::new();
+        self.len = 0;
+    }
+
+    // Private method to walk down to the node for `key`, if it exists
+    fn find_node(&self, key: &str) -> Option<&TrieNode<V>> {
+        let mut node = &self.root;
+        for c in key.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+
+    // Private method to remove the value at `key` below `node`, pruning
+    // emptied children on the way back up
+    fn remove_from(node: &mut TrieNode<V>, key: &[char]) -> Option<V> {
+        let Some((&c, rest)) = key.split_first() else {
+            return node.value.take();
+        };
+
+        let child = node.children.get_mut(&c)?;
+        let removed = Self::remove_from(child, rest)?;
+        if child.is_prunable() {
+            node.children.remove(&c);
+        }
+        Some(removed)
+    }
+}
+
+// A depth-first iterator over the keys below a node
+//
+// Children are pushed in reverse order so they are popped in sorted order,
+// and a node's own key is yielded before any of its extensions.
+struct KeysWithPrefix<'a, V> {
+    stack: Vec<(String, &'a TrieNode<V>)>,
+}
+
+impl<V> Iterator for KeysWithPrefix<'_, V> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((key, node)) = self.stack.pop() {
+            for (&c, child) in node.children.iter().rev() {
+                let mut child_key = key.clone();
+                child_key.push(c);
+                self.stack.push((child_key, child));
+            }
+            if node.value.is_some() {
+                return Some(key);
+            }
+        }
+        None
+    }
+}
+
+impl<V> Default for Trie<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for Trie<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Trie").field("len", &self.len).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut trie = Trie::new();
+        assert!(trie.is_empty());
+
+        assert_eq!(trie.insert("car", 1), None);
+        assert_eq!(trie.insert("cart", 2), None);
+        assert_eq!(trie.insert("cat", 3), None);
+        assert_eq!(trie.len(), 3);
+
+        assert_eq!(trie.get("car"), Some(&1));
+        assert_eq!(trie.get("cart"), Some(&2));
+        assert_eq!(trie.get("cat"), Some(&3));
+
+        // Prefixes and extensions of stored keys are not keys themselves
+        assert_eq!(trie.get("ca"), None);
+        assert_eq!(trie.get("carts"), None);
+        assert!(!trie.contains_key(""));
+    }
+
+    #[test]
+    fn test_insert_replaces() {
+        let mut trie = Trie::new();
+        trie.insert("car", 1);
+        assert_eq!(trie.insert("car", 10), Some(1));
+        assert_eq!(trie.len(), 1);
+        assert_eq!(trie.get("car"), Some(&10));
+
+        if let Some(value) = trie.get_mut("car") {
+            *value += 1;
+        }
+        assert_eq!(trie.get("car"), Some(&11));
+    }
+
+    #[test]
+    fn test_remove_keeps_shared_prefix() {
+        let mut trie = Trie::new();
+        trie.insert("car", 1);
+        trie.insert("cart", 2);
+        trie.insert("cat", 3);
+
+        assert_eq!(trie.remove("car"), Some(1));
+        assert_eq!(trie.len(), 2);
+        assert!(!trie.contains_key("car"));
+        assert_eq!(trie.get("cart"), Some(&2));
+        assert_eq!(trie.get("cat"), Some(&3));
+
+        // Removing a missing key or a bare prefix does nothing
+        assert_eq!(trie.remove("car"), None);
+        assert_eq!(trie.remove("ca"), None);
+        assert_eq!(trie.len(), 2);
+    }
+
+    #[test]
+    fn test_remove_prunes_nodes() {
+        let mut trie = Trie::new();
+        trie.insert("cart", 2);
+        trie.insert("cat", 3);
+
+        assert_eq!(trie.remove("cart"), Some(2));
+        // The "r" and "t" nodes below "ca" are gone, but "cat" is intact
+        let ca = trie.find_node("ca").unwrap();
+        assert_eq!(ca.children.len(), 1);
+        assert_eq!(trie.get("cat"), Some(&3));
+
+        assert_eq!(trie.remove("cat"), Some(3));
+        assert!(trie.is_empty());
+        assert!(trie.root.children.is_empty());
+    }
+
+    #[test]
+    fn test_keys_with_prefix() {
+        let mut trie = Trie::new();
+        for (i, word) in ["cat", "car", "cart", "carbon", "dog", "do", "zebra"]
+            .iter()
+            .enumerate()
+        {
+            trie.insert(word, i);
+        }
+
+        let completions: Vec<String> = trie.keys_with_prefix("car").collect();
+        assert_eq!(completions, ["car", "carbon", "cart"]);
+
+        let completions: Vec<String> = trie.keys_with_prefix("do").collect();
+        assert_eq!(completions, ["do", "dog"]);
+
+        // The empty prefix yields every key in sorted order
+        let all: Vec<String> = trie.keys_with_prefix("").collect();
+        assert_eq!(all, ["car", "carbon", "cart", "cat", "do", "dog", "zebra"]);
+
+        assert_eq!(trie.keys_with_prefix("cow").count(), 0);
+        assert_eq!(trie.keys_with_prefix("carts").count(), 0);
+    }
+
+    #[test]
+    fn test_starts_with() {
+        let mut trie = Trie::new();
+        assert!(!trie.starts_with(""));
+
+        trie.insert("cart", 1);
+        assert!(trie.starts_with(""));
+        assert!(trie.starts_with("ca"));
+        assert!(trie.starts_with("cart"));
+        assert!(!trie.starts_with("carts"));
+        assert!(!trie.starts_with("d"));
+
+        trie.remove("cart");
+        assert!(!trie.starts_with("ca"));
+    }
+
+    #[test]
+    fn test_empty_and_unicode_keys() {
+        let mut trie = Trie::new();
+        trie.insert("", 0);
+        trie.insert("héllo", 1);
+
+        assert_eq!(trie.get(""), Some(&0));
+        assert_eq!(trie.get("héllo"), Some(&1));
+        assert_eq!(trie.remove(""), Some(0));
+        assert_eq!(trie.get("héllo"), Some(&1));
+    }
+}
@@ -3,11 +3,94 @@
 //! This module provides an optimized vector (dynamic array) implementation
 //! with focus on performance and memory efficiency.
 
-use std::alloc::{self, Layout};
-use std::marker::PhantomData;
-use std::mem;
-use std::ops::{Deref, DerefMut, Index, IndexMut};
-use std::ptr::{self, NonNull};
+use ::alloc::alloc::{self, Layout};
+#[cfg(target_has_atomic = "ptr")]
+use ::alloc::sync::Arc;
+use ::alloc::vec::Vec;
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem::{self, MaybeUninit};
+use core::ops::{Bound, Deref, DerefMut, Index, IndexMut, Range, RangeBounds};
+use core::ptr::{self, NonNull};
+
+use crate::allocator::{Allocator, Global};
+use crate::linked_list::LinkedList;
+
+#[cfg(feature = "rayon")]
+mod par_iter;
+
+// Smallest capacity allocated when a vector first grows
+const MIN_NON_ZERO_CAPACITY: usize = 4;
+
+// Returns the layout of a buffer holding `capacity` elements of `T`,
+// panicking if its size in bytes would overflow `isize`
+fn array_layout<T>(capacity: usize) -> Layout {
+    Layout::array::<T>(capacity).expect("capacity overflow")
+}
+
+// Resolves `range` against a vector of length `len`, panicking if it is
+// decreasing or extends past the end
+fn slice_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).expect("range start overflow"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).expect("range end overflow"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end, "range starts at {} but ends at {}", start, end);
+    assert!(end <= len, "range end {} out of bounds for length {}", end, len);
+    start..end
+}
+
+/// Types for which the all-zero bit pattern is a valid value
+///
+/// This lets [`Vector::resize_zeroed`] fill new elements with a single
+/// `memset` instead of writing them one by one.
+///
+/// # Safety
+///
+/// An implementor must be valid when every byte of it is zero.
+pub unsafe trait Zeroable: Copy {}
+
+macro_rules! impl_zeroable {
+    ($($ty:ty),*) => {
+        $(unsafe impl Zeroable for $ty {})*
+    };
+}
+
+impl_zeroable!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char);
+
+/// The error returned by the fallible allocation methods of [`Vector`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The requested capacity exceeds `isize::MAX` bytes
+    CapacityOverflow,
+    /// The allocator failed to provide a buffer with this layout
+    AllocError {
+        /// The layout of the failed allocation
+        layout: Layout,
+    },
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryReserveError::CapacityOverflow => f.write_str("capacity overflow"),
+            TryReserveError::AllocError { layout } => {
+                write!(f, "memory allocation of {} bytes failed", layout.size())
+            }
+        }
+    }
+}
+
+impl core::error::Error for TryReserveError {}
 
 /// A high-performance vector implementation
 ///
@@ -30,55 +113,179 @@ use std::ptr::{self, NonNull};
 /// assert_eq!(vec[2], 3);
 /// assert_eq!(vec.len(), 3);
 /// ```
-pub struct Vector<T> {
+///
+/// The buffer is obtained from the allocator `A`, which defaults to the
+/// global allocator. Use [`new_in`] or [`with_capacity_in`] to supply a
+/// different one.
+///
+/// [`new_in`]: Vector::new_in
+/// [`with_capacity_in`]: Vector::with_capacity_in
+pub struct Vector<T, A: Allocator = Global> {
     ptr: NonNull<T>,
     capacity: usize,
     len: usize,
+    alloc: A,
     _marker: PhantomData<T>,
 }
 
-// Implement Default for Vector<T>
-impl<T> Default for Vector<T> {
+// Implement Default for Vector<T, A>
+impl<T, A: Allocator + Default> Default for Vector<T, A> {
     fn default() -> Self {
-        Self::new()
+        Self::new_in(A::default())
     }
 }
 
-// Safe to implement Send and Sync if T is Send and Sync
-unsafe impl<T: Send> Send for Vector<T> {}
-unsafe impl<T: Sync> Sync for Vector<T> {}
+// Safe to implement Send and Sync if T and the allocator are Send and Sync
+unsafe impl<T: Send, A: Allocator + Send> Send for Vector<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for Vector<T, A> {}
 
 impl<T> Vector<T> {
     /// Creates a new, empty vector
     pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    /// Creates a new vector with the specified capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+
+    /// Converts the vector into a raw parts tuple
+    pub fn into_raw_parts(self) -> (*mut T, usize, usize) {
+        let result = (self.ptr.as_ptr(), self.len, self.capacity);
+        
+        // Avoid running the destructor
+        mem::forget(self);
+        
+        result
+    }
+
+    /// Consumes the vector and returns a mutable slice over its elements
+    ///
+    /// This intentionally leaks the allocation: the elements are never
+    /// dropped and the buffer is never freed, so the slice may be given any
+    /// lifetime, including `'static`. It is meant for data that lives for
+    /// the rest of the program, such as lookup tables built at startup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let table: &'static mut [u32] = Vector::from([1, 2, 3]).leak();
+    /// table[0] = 10;
+    /// assert_eq!(table, &[10, 2, 3]);
+    /// ```
+    pub fn leak<'a>(self) -> &'a mut [T] {
+        let (ptr, len, _) = self.into_raw_parts();
+        unsafe { core::slice::from_raw_parts_mut(ptr, len) }
+    }
+
+    /// Consumes the vector and returns its elements as a shared, immutable
+    /// slice
+    ///
+    /// The `Arc` keeps its reference counts in the same allocation as the
+    /// elements, so the buffer cannot be handed over as is: the elements are
+    /// moved into a new allocation with a single copy and the old buffer is
+    /// freed. Cloning the result afterwards only bumps the reference count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let frozen = Vector::from([1, 2, 3]).freeze();
+    /// let shared = frozen.clone();
+    /// assert_eq!(&*shared, &[1, 2, 3]);
+    /// ```
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn freeze(self) -> Arc<[T]> {
+        Arc::from(Vec::from(self))
+    }
+
+    /// Creates a vector from raw parts
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe because it assumes the raw parts are valid.
+    pub unsafe fn from_raw_parts(ptr: *mut T, len: usize, capacity: usize) -> Self {
+        Self {
+            ptr: NonNull::new_unchecked(ptr),
+            len,
+            capacity,
+            alloc: Global,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Allocator> Vector<T, A> {
+    /// Creates a new, empty vector that will allocate from `alloc`
+    ///
+    /// A vector of zero-sized elements never allocates, so its capacity is
+    /// `usize::MAX` from the start.
+    pub fn new_in(alloc: A) -> Self {
         Self {
             ptr: NonNull::dangling(),
-            capacity: 0,
+            capacity: if mem::size_of::<T>() == 0 { usize::MAX } else { 0 },
             len: 0,
+            alloc,
             _marker: PhantomData,
         }
     }
 
-    /// Creates a new vector with the specified capacity
-    pub fn with_capacity(capacity: usize) -> Self {
-        if capacity == 0 {
-            return Self::new();
+    /// Creates a new vector with the specified capacity, allocated from `alloc`
+    ///
+    /// # Panics
+    ///
+    /// Panics with "capacity overflow" if the buffer size in bytes would
+    /// exceed `isize::MAX`. If the allocator fails, this calls
+    /// [`handle_alloc_error`](alloc::handle_alloc_error), which aborts by
+    /// default.
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        if capacity == 0 || mem::size_of::<T>() == 0 {
+            return Self::new_in(alloc);
         }
 
         // Allocate memory for the specified capacity
-        let layout = Layout::array::<T>(capacity).unwrap();
-        let ptr = unsafe { 
-            NonNull::new(alloc::alloc(layout) as *mut T).unwrap() 
-        };
+        let layout = array_layout::<T>(capacity);
+        let ptr = alloc
+            .allocate(layout)
+            .unwrap_or_else(|_| alloc::handle_alloc_error(layout))
+            .cast();
 
         Self {
             ptr,
             capacity,
             len: 0,
+            alloc,
             _marker: PhantomData,
         }
     }
 
+    /// Returns a raw pointer to the vector's buffer
+    ///
+    /// The pointer is valid for reads of `len` elements for as long as the
+    /// vector is not modified or dropped. If the vector has not allocated,
+    /// the pointer is dangling but properly aligned.
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr.as_ptr()
+    }
+
+    /// Returns a raw mutable pointer to the vector's buffer
+    ///
+    /// The pointer is valid for reads and writes of `len` elements for as
+    /// long as the vector is not reallocated or dropped. If the vector has
+    /// not allocated, the pointer is dangling but properly aligned.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    /// Returns a reference to the underlying allocator
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
     /// Returns the current length of the vector
     pub fn len(&self) -> usize {
         self.len
@@ -90,10 +297,26 @@ impl<T> Vector<T> {
     }
 
     /// Returns the current capacity of the vector
+    ///
+    /// This is always `usize::MAX` for zero-sized elements.
     pub fn capacity(&self) -> usize {
         self.capacity
     }
 
+    /// Reserves capacity for at least `additional` more elements
+    ///
+    /// Does nothing if the capacity is already sufficient. Otherwise the
+    /// buffer grows as it would on a push, or to exactly `len + additional`
+    /// if that is larger.
+    ///
+    /// # Panics
+    ///
+    /// Panics with "capacity overflow" if the new capacity would overflow.
+    pub fn reserve(&mut self, additional: usize) {
+        let min_capacity = self.len.checked_add(additional).expect("capacity overflow");
+        self.grow_to(min_capacity);
+    }
+
     /// Adds an element to the end of the vector
     pub fn push(&mut self, value: T) {
         if self.len == self.capacity {
@@ -107,6 +330,75 @@ impl<T> Vector<T> {
         self.len += 1;
     }
 
+    /// Tries to reserve capacity for at least `additional` more elements
+    ///
+    /// This is the fallible counterpart of [`reserve`](Vector::reserve): if
+    /// the capacity would overflow or the allocator fails, the error is
+    /// returned and the vector is left unchanged.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let min_capacity = self.len.checked_add(additional).ok_or(TryReserveError::CapacityOverflow)?;
+        self.try_grow_to(min_capacity)
+    }
+
+    /// Tries to add an element to the end of the vector, growing it through
+    /// the fallible allocation path if needed
+    ///
+    /// If growing fails, the error is returned and the vector is left
+    /// unchanged. The value is dropped in that case; use
+    /// [`try_reserve`](Vector::try_reserve) followed by
+    /// [`push_within_capacity`](Vector::push_within_capacity) to keep it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::new();
+    /// vec.try_push(1).expect("allocation failed");
+    /// assert_eq!(&*vec, &[1]);
+    /// ```
+    pub fn try_push(&mut self, value: T) -> Result<(), TryReserveError> {
+        if self.len == self.capacity {
+            let min_capacity = self.capacity.checked_add(1).ok_or(TryReserveError::CapacityOverflow)?;
+            self.try_grow_to(min_capacity)?;
+        }
+
+        unsafe {
+            ptr::write(self.ptr.as_ptr().add(self.len), value);
+        }
+
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Adds an element to the end of the vector only if it fits in the
+    /// current capacity
+    ///
+    /// This never allocates. If the vector is full, the value is handed back
+    /// in the `Err` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::with_capacity(1);
+    /// assert_eq!(vec.push_within_capacity(1), Ok(()));
+    /// assert_eq!(vec.push_within_capacity(2), Err(2));
+    /// ```
+    pub fn push_within_capacity(&mut self, value: T) -> Result<(), T> {
+        if self.len == self.capacity {
+            return Err(value);
+        }
+
+        unsafe {
+            ptr::write(self.ptr.as_ptr().add(self.len), value);
+        }
+
+        self.len += 1;
+        Ok(())
+    }
+
     /// Removes the last element from the vector and returns it
     pub fn pop(&mut self) -> Option<T> {
         if self.len == 0 {
@@ -119,6 +411,30 @@ impl<T> Vector<T> {
         }
     }
 
+    /// Removes and returns the last element if `pred` returns true for it
+    ///
+    /// The predicate receives a mutable reference, so it may modify the
+    /// element before deciding. If the vector is empty or the predicate
+    /// returns false, the element stays in place and `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::from([1, 2, 3, 4]);
+    /// assert_eq!(vec.pop_if(|x| *x % 2 == 0), Some(4));
+    /// assert_eq!(vec.pop_if(|x| *x % 2 == 0), None);
+    /// assert_eq!(&*vec, &[1, 2, 3]);
+    /// ```
+    pub fn pop_if<F: FnOnce(&mut T) -> bool>(&mut self, pred: F) -> Option<T> {
+        if pred(self.last_mut()?) {
+            self.pop()
+        } else {
+            None
+        }
+    }
+
     /// Gets a reference to an element at the specified index
     pub fn get(&self, index: usize) -> Option<&T> {
         if index >= self.len {
@@ -141,178 +457,2755 @@ impl<T> Vector<T> {
         }
     }
 
-    // Private method to grow the vector's capacity
-    fn grow(&mut self) {
-        let new_capacity = if self.capacity == 0 { 1 } else { self.capacity * 2 };
-        
-        let ptr = if self.capacity == 0 {
-            let layout = Layout::array::<T>(new_capacity).unwrap();
-            unsafe { 
-                NonNull::new(alloc::alloc(layout) as *mut T).unwrap() 
-            }
-        } else {
-            let old_layout = Layout::array::<T>(self.capacity).unwrap();
-            let new_layout = Layout::array::<T>(new_capacity).unwrap();
-            
-            unsafe {
-                let ptr = alloc::realloc(
-                    self.ptr.as_ptr() as *mut u8,
-                    old_layout,
-                    new_layout.size(),
-                );
-                NonNull::new(ptr as *mut T).unwrap()
-            }
-        };
+    /// Returns a reference to the first element, or `None` if the vector is empty
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
 
-        self.ptr = ptr;
-        self.capacity = new_capacity;
+    /// Returns a mutable reference to the first element, or `None` if the
+    /// vector is empty
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
     }
 
-    /// Converts the vector into a raw parts tuple
-    pub fn into_raw_parts(self) -> (*mut T, usize, usize) {
-        let result = (self.ptr.as_ptr(), self.len, self.capacity);
-        
-        // Avoid running the destructor
-        mem::forget(self);
-        
-        result
+    /// Returns a reference to the last element, or `None` if the vector is empty
+    pub fn last(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|index| self.get(index))
     }
 
-    /// Creates a vector from raw parts
-    ///
-    /// # Safety
-    ///
-    /// This function is unsafe because it assumes the raw parts are valid.
-    pub unsafe fn from_raw_parts(ptr: *mut T, len: usize, capacity: usize) -> Self {
-        Self {
-            ptr: NonNull::new_unchecked(ptr),
-            len,
-            capacity,
-            _marker: PhantomData,
-        }
+    /// Returns a mutable reference to the last element, or `None` if the
+    /// vector is empty
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.len.checked_sub(1).and_then(move |index| self.get_mut(index))
     }
-}
 
-impl<T> Drop for Vector<T> {
-    fn drop(&mut self) {
-        if self.capacity == 0 {
-            return;
-        }
+    /// Returns the first element and the rest of the vector, or `None` if it
+    /// is empty
+    pub fn split_first(&self) -> Option<(&T, &[T])> {
+        self.deref().split_first()
+    }
 
-        // Drop all elements
-        for i in 0..self.len {
-            unsafe {
-                ptr::drop_in_place(self.ptr.as_ptr().add(i));
-            }
-        }
+    /// Returns the first element and the rest of the vector mutably, or
+    /// `None` if it is empty
+    pub fn split_first_mut(&mut self) -> Option<(&mut T, &mut [T])> {
+        self.deref_mut().split_first_mut()
+    }
 
-        // Deallocate the memory
-        unsafe {
-            let layout = Layout::array::<T>(self.capacity).unwrap();
-            alloc::dealloc(self.ptr.as_ptr() as *mut u8, layout);
-        }
+    /// Returns the last element and the rest of the vector, or `None` if it
+    /// is empty
+    pub fn split_last(&self) -> Option<(&T, &[T])> {
+        self.deref().split_last()
     }
-}
 
-impl<T> Deref for Vector<T> {
-    type Target = [T];
+    /// Returns the last element and the rest of the vector mutably, or
+    /// `None` if it is empty
+    pub fn split_last_mut(&mut self) -> Option<(&mut T, &mut [T])> {
+        self.deref_mut().split_last_mut()
+    }
 
-    fn deref(&self) -> &Self::Target {
-        unsafe {
-            std::slice::from_raw_parts(self.ptr.as_ptr(), self.len)
+    /// Returns a new vector holding `f` applied to each element, in order
+    ///
+    /// The result is allocated once with room for exactly `len` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let vec = Vector::from([1, 2, 3]);
+    /// assert_eq!(&*vec.map(|x| x * 10), &[10, 20, 30]);
+    /// ```
+    pub fn map<U, F: FnMut(&T) -> U>(&self, mut f: F) -> Vector<U> {
+        let mut mapped = Vector::with_capacity(self.len);
+        for value in self.iter() {
+            mapped.push(f(value));
         }
+        mapped
     }
-}
 
-impl<T> DerefMut for Vector<T> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe {
-            std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len)
+    /// Consumes the vector and moves its elements, in order, into a new
+    /// linked list
+    ///
+    /// Each element is moved out of the buffer exactly once, and the buffer
+    /// is freed once the last one has been moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let list = Vector::from([1, 2, 3]).into_linked_list();
+    /// assert_eq!(list.front(), Some(&1));
+    /// assert_eq!(list.back(), Some(&3));
+    /// ```
+    pub fn into_linked_list(self) -> LinkedList<T> {
+        let mut list = LinkedList::new();
+        for value in self {
+            list.push_back(value);
         }
+        list
     }
-}
-
-impl<T, I: std::slice::SliceIndex<[T]>> Index<I> for Vector<T> {
-    type Output = I::Output;
 
-    fn index(&self, index: I) -> &Self::Output {
-        Index::index(&**self, index)
+    /// Returns an iterator over the elements in order
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.deref().iter()
     }
-}
 
-impl<T, I: std::slice::SliceIndex<[T]>> IndexMut<I> for Vector<T> {
-    fn index_mut(&mut self, index: I) -> &mut Self::Output {
-        IndexMut::index_mut(&mut **self, index)
+    /// Returns an iterator that allows modifying each element in order
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.deref_mut().iter_mut()
     }
-}
 
-impl<T: Clone> Clone for Vector<T> {
-    fn clone(&self) -> Self {
-        let mut new_vec = Self::with_capacity(self.capacity);
-        
-        for i in 0..self.len {
-            unsafe {
-                let item = ptr::read(self.ptr.as_ptr().add(i));
-                new_vec.push(item.clone());
-            }
-        }
-        
-        new_vec
+    /// Returns an iterator over all overlapping windows of `size` elements
+    ///
+    /// If the vector is shorter than `size`, the iterator yields nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn windows(&self, size: usize) -> core::slice::Windows<'_, T> {
+        self.deref().windows(size)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Returns an iterator over non-overlapping chunks of `size` elements
+    ///
+    /// If the length is not a multiple of `size`, the last chunk is shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn chunks(&self, size: usize) -> core::slice::Chunks<'_, T> {
+        self.deref().chunks(size)
+    }
 
-    #[test]
-    fn test_new() {
-        let vec: Vector<i32> = Vector::new();
-        assert_eq!(vec.len(), 0);
-        assert_eq!(vec.capacity(), 0);
+    /// Returns an iterator over non-overlapping mutable chunks of `size`
+    /// elements
+    ///
+    /// If the length is not a multiple of `size`, the last chunk is shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn chunks_mut(&mut self, size: usize) -> core::slice::ChunksMut<'_, T> {
+        self.deref_mut().chunks_mut(size)
     }
 
-    #[test]
-    fn test_with_capacity() {
-        let vec: Vector<i32> = Vector::with_capacity(10);
-        assert_eq!(vec.len(), 0);
-        assert_eq!(vec.capacity(), 10);
+    /// Returns an iterator over non-overlapping chunks of exactly `size`
+    /// elements
+    ///
+    /// If the length is not a multiple of `size`, the leftover elements are
+    /// not yielded; they are available from the iterator's `remainder`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn chunks_exact(&self, size: usize) -> core::slice::ChunksExact<'_, T> {
+        self.deref().chunks_exact(size)
     }
 
-    #[test]
-    fn test_push_pop() {
-        let mut vec = Vector::new();
-        vec.push(1);
-        vec.push(2);
-        vec.push(3);
-        
-        assert_eq!(vec.len(), 3);
-        assert_eq!(vec.pop(), Some(3));
-        assert_eq!(vec.len(), 2);
-        assert_eq!(vec.pop(), Some(2));
-        assert_eq!(vec.pop(), Some(1));
-        assert_eq!(vec.pop(), None);
-        assert_eq!(vec.len(), 0);
+    /// Splits the vector into a slice of `N`-element arrays and a remainder
+    /// shorter than `N`
+    ///
+    /// Unlike [`chunks_exact`](Vector::chunks_exact), the chunks have a
+    /// length known at compile time, which lets loops over them be unrolled
+    /// or vectorized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let vec = Vector::from([1, 2, 3, 4, 5]);
+    /// let (chunks, rest) = vec.as_chunks::<2>();
+    /// assert_eq!(chunks, &[[1, 2], [3, 4]]);
+    /// assert_eq!(rest, &[5]);
+    /// ```
+    pub fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        let chunks = self.len / N;
+        let (head, rest) = self.deref().split_at(chunks * N);
+        // `[T; N]` has the layout of `N` consecutive `T`s, and `head` holds
+        // exactly `chunks * N` of them
+        let head = unsafe { core::slice::from_raw_parts(head.as_ptr().cast::<[T; N]>(), chunks) };
+        (head, rest)
     }
 
-    #[test]
+    /// Splits the vector into a mutable slice of `N`-element arrays and a
+    /// mutable remainder shorter than `N`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub fn as_chunks_mut<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T]) {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        let chunks = self.len / N;
+        let (head, rest) = self.deref_mut().split_at_mut(chunks * N);
+        let head = unsafe { core::slice::from_raw_parts_mut(head.as_mut_ptr().cast::<[T; N]>(), chunks) };
+        (head, rest)
+    }
+
+    /// Consumes the vector and returns an iterator over owned `N`-element
+    /// arrays
+    ///
+    /// The last `len % N` elements do not fill a whole array; they are never
+    /// yielded and can be inspected with [`ArrayChunks::remainder`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut chunks = Vector::from([1, 2, 3, 4, 5]).into_array_chunks::<2>();
+    /// assert_eq!(chunks.next(), Some([1, 2]));
+    /// assert_eq!(chunks.next(), Some([3, 4]));
+    /// assert_eq!(chunks.next(), None);
+    /// assert_eq!(chunks.remainder(), &[5]);
+    /// ```
+    pub fn into_array_chunks<const N: usize>(self) -> ArrayChunks<T, N, A> {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        ArrayChunks { iter: self.into_iter() }
+    }
+
+    /// Splits the vector into an unaligned prefix, a middle of `U` values
+    /// aligned for `U`, and an unaligned suffix
+    ///
+    /// This is meant for SIMD kernels on stable Rust: with `U` a lane type
+    /// such as `#[repr(align(16))] struct F32x4([f32; 4])`, the middle can be
+    /// processed with aligned loads while the prefix and suffix take the
+    /// scalar path. The middle is as large as possible, but the buffer is
+    /// only aligned for `T`, so the prefix may be non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The elements in the middle are reinterpreted as `U`, so this has the
+    /// same requirements as [`mem::transmute`] from `[T; N]` to `U`: every
+    /// bit pattern produced this way must be a valid `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// #[repr(C, align(16))]
+    /// struct F32x4([f32; 4]);
+    ///
+    /// let vec: Vector<f32> = (0..10).map(|i| i as f32).collect();
+    /// let (prefix, middle, suffix) = unsafe { vec.align_to::<F32x4>() };
+    /// assert_eq!(prefix.len() + middle.len() * 4 + suffix.len(), 10);
+    /// ```
+    pub unsafe fn align_to<U>(&self) -> (&[T], &[U], &[T]) {
+        self.deref().align_to()
+    }
+
+    /// Splits the vector into an unaligned prefix, a middle of `U` values
+    /// aligned for `U`, and an unaligned suffix, all mutable
+    ///
+    /// See [`align_to`](Vector::align_to).
+    ///
+    /// # Safety
+    ///
+    /// Every bit pattern of the middle elements must be a valid `U`, and
+    /// every bit pattern written through the `U` values must be valid for
+    /// `T`.
+    pub unsafe fn align_to_mut<U>(&mut self) -> (&mut [T], &mut [U], &mut [T]) {
+        self.deref_mut().align_to_mut()
+    }
+
+    /// Gets a reference to an element at the specified index, without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is undefined behavior,
+    /// even if the resulting reference is not used. The caller must ensure
+    /// that `index < self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::new();
+    /// vec.push(1);
+    /// vec.push(2);
+    ///
+    /// if 1 < vec.len() {
+    ///     assert_eq!(unsafe { *vec.get_unchecked(1) }, 2);
+    /// }
+    /// ```
+    pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+        debug_assert!(index < self.len);
+        &*self.ptr.as_ptr().add(index)
+    }
+
+    /// Gets a mutable reference to an element at the specified index, without
+    /// bounds checking
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is undefined behavior,
+    /// even if the resulting reference is not used. The caller must ensure
+    /// that `index < self.len()`.
+    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        debug_assert!(index < self.len);
+        &mut *self.ptr.as_ptr().add(index)
+    }
+
+    /// Swaps the elements at indices `a` and `b`
+    ///
+    /// The elements are moved, not cloned, so this works for any `T`. If
+    /// `a == b` the vector is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(
+            a < self.len && b < self.len,
+            "swap indices ({}, {}) out of bounds for length {}",
+            a,
+            b,
+            self.len
+        );
+
+        unsafe {
+            // ptr::swap allows the two pointers to be equal
+            ptr::swap(self.ptr.as_ptr().add(a), self.ptr.as_ptr().add(b));
+        }
+    }
+
+    /// Reverses the order of the elements in place
+    ///
+    /// Mirrored pairs are swapped, so elements are moved, not cloned.
+    pub fn reverse(&mut self) {
+        let len = self.len;
+        for i in 0..len / 2 {
+            unsafe {
+                ptr::swap(self.ptr.as_ptr().add(i), self.ptr.as_ptr().add(len - 1 - i));
+            }
+        }
+    }
+
+    /// Returns whether the vector contains an element equal to `x`
+    ///
+    /// This is a linear scan. For a sorted vector,
+    /// [`partition_point`](Vector::partition_point) finds an element in
+    /// O(log n).
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.deref().contains(x)
+    }
+
+    /// Returns the index of the first element for which `pred` returns false
+    ///
+    /// The vector is assumed to be partitioned by the predicate: all elements
+    /// for which it returns true come before all elements for which it
+    /// returns false. The search is a binary search, so it runs in O(log n).
+    /// If the vector is not partitioned, the result is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::new();
+    /// vec.push(2);
+    /// vec.push(4);
+    /// vec.push(6);
+    /// vec.push(8);
+    ///
+    /// assert_eq!(vec.partition_point(|&x| x < 5), 2);
+    /// ```
+    pub fn partition_point<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        let mut low = 0;
+        let mut high = self.len;
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if pred(unsafe { &*self.ptr.as_ptr().add(mid) }) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        low
+    }
+
+    /// Inserts `value` into a sorted vector, keeping it sorted, and returns
+    /// the index it was inserted at
+    ///
+    /// The insertion point is found by binary search and placed after any
+    /// elements equal to `value`. If the vector is not sorted, the position
+    /// is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::from([1, 3, 5]);
+    /// assert_eq!(vec.binary_insert(4), 2);
+    /// assert_eq!(&*vec, &[1, 3, 4, 5]);
+    /// ```
+    pub fn binary_insert(&mut self, value: T) -> usize
+    where
+        T: Ord,
+    {
+        self.binary_insert_by(value, T::cmp)
+    }
+
+    /// Inserts `value` into a vector sorted by `compare`, keeping it sorted,
+    /// and returns the index it was inserted at
+    ///
+    /// Like [`binary_insert`](Vector::binary_insert), the value is placed
+    /// after any elements that compare equal to it.
+    pub fn binary_insert_by<F: FnMut(&T, &T) -> Ordering>(&mut self, value: T, mut compare: F) -> usize {
+        let index = self.partition_point(|x| compare(x, &value) != Ordering::Greater);
+        self.insert(index, value);
+        index
+    }
+
+    /// Consumes the vector, keeping only the elements for which `pred` returns true
+    ///
+    /// The kept elements are compacted in place, so no new buffer is
+    /// allocated. Every rejected element is dropped exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::new();
+    /// vec.push(1);
+    /// vec.push(2);
+    /// vec.push(3);
+    /// vec.push(4);
+    ///
+    /// let evens = vec.into_filter(|x| x % 2 == 0);
+    /// assert_eq!(&*evens, &[2, 4]);
+    /// ```
+    pub fn into_filter<F: FnMut(&T) -> bool>(mut self, pred: F) -> Self {
+        self.retain(pred);
+        self
+    }
+
+    /// Retains only the elements for which `f` returns true
+    ///
+    /// The kept elements stay in their original order, and every rejected
+    /// element is dropped exactly once.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.retain_count(f);
+    }
+
+    /// Retains only the elements for which `f` returns true, and returns how
+    /// many elements were removed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::from([1, 2, 3, 4, 5]);
+    /// assert_eq!(vec.retain_count(|&x| x % 2 == 1), 2);
+    /// assert_eq!(&*vec, &[1, 3, 5]);
+    /// ```
+    pub fn retain_count<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        let original_len = self.len;
+        self.retain_mut(|x| f(x));
+        original_len - self.len
+    }
+
+    /// Retains only the elements for which `f` returns true, passing each
+    /// element mutably
+    ///
+    /// This lets the predicate update the elements it keeps in the same pass.
+    /// If `f` or an element's destructor panics, the vector is left holding
+    /// the elements kept so far followed by those not yet visited, so none
+    /// are leaked or dropped twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::from([3, -1, 4, -1, 5]);
+    /// vec.retain_mut(|x| {
+    ///     *x *= 2;
+    ///     *x > 0
+    /// });
+    /// assert_eq!(&*vec, &[6, 8, 10]);
+    /// ```
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        // Closes the gap left by removed elements when dropped, whether the
+        // loop finished or unwound
+        struct BackshiftOnDrop<'a, T, A: Allocator> {
+            vec: &'a mut Vector<T, A>,
+            processed: usize,
+            deleted: usize,
+            original_len: usize,
+        }
+
+        impl<T, A: Allocator> Drop for BackshiftOnDrop<'_, T, A> {
+            fn drop(&mut self) {
+                if self.deleted > 0 {
+                    unsafe {
+                        let base = self.vec.ptr.as_ptr();
+                        ptr::copy(
+                            base.add(self.processed),
+                            base.add(self.processed - self.deleted),
+                            self.original_len - self.processed,
+                        );
+                    }
+                }
+                self.vec.len = self.original_len - self.deleted;
+            }
+        }
+
+        let original_len = self.len;
+        // Hide every element while the guard owns the bookkeeping, so a
+        // panic can never expose a moved-out or dropped slot
+        self.len = 0;
+        let mut guard = BackshiftOnDrop {
+            vec: self,
+            processed: 0,
+            deleted: 0,
+            original_len,
+        };
+
+        while guard.processed != original_len {
+            unsafe {
+                let cur = guard.vec.ptr.as_ptr().add(guard.processed);
+                if !f(&mut *cur) {
+                    // Count the element as gone before dropping it, so a
+                    // panicking destructor does not get it dropped again
+                    guard.processed += 1;
+                    guard.deleted += 1;
+                    ptr::drop_in_place(cur);
+                    continue;
+                }
+                if guard.deleted > 0 {
+                    ptr::copy_nonoverlapping(cur, cur.sub(guard.deleted), 1);
+                }
+                guard.processed += 1;
+            }
+        }
+    }
+
+    /// Removes consecutive repeated elements
+    ///
+    /// If the vector is sorted, this removes all duplicates.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive elements that `same` considers equal
+    ///
+    /// `same(a, b)` is called with each element `a` and the last element `b`
+    /// that was kept before it; if it returns true, `a` is removed. Every
+    /// removed element is dropped exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::from(["apple", "avocado", "banana", "blueberry", "apricot"]);
+    /// vec.dedup_by(|a, b| a.as_bytes()[0] == b.as_bytes()[0]);
+    /// assert_eq!(&*vec, &["apple", "banana", "apricot"]);
+    /// ```
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same: F) {
+        let original_len = self.len;
+        if original_len <= 1 {
+            return;
+        }
+        // Leak the tail while compacting so a panicking closure cannot cause
+        // a double drop
+        self.len = 1;
+
+        let mut kept = 1;
+        for i in 1..original_len {
+            unsafe {
+                let src = self.ptr.as_ptr().add(i);
+                let prev = self.ptr.as_ptr().add(kept - 1);
+                if same(&mut *src, &mut *prev) {
+                    ptr::drop_in_place(src);
+                } else {
+                    if i != kept {
+                        ptr::copy_nonoverlapping(src, self.ptr.as_ptr().add(kept), 1);
+                    }
+                    kept += 1;
+                    self.len = kept;
+                }
+            }
+        }
+    }
+
+    /// Returns a new vector holding the first occurrence of each element
+    ///
+    /// Unlike [`dedup`](Vector::dedup), this also removes duplicates that are
+    /// not adjacent, and leaves `self` untouched. Each kept element is cloned
+    /// once; seen values are tracked by reference in a `HashSet`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let vec = Vector::from([1, 2, 1, 3, 2, 4]);
+    /// assert_eq!(&*vec.unique(), &[1, 2, 3, 4]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn unique(&self) -> Vector<T>
+    where
+        T: Clone + core::hash::Hash + Eq,
+    {
+        let mut seen = std::collections::HashSet::with_capacity(self.len);
+        let mut unique = Vector::new();
+        for value in self.iter() {
+            if seen.insert(value) {
+                unique.push(value.clone());
+            }
+        }
+        unique
+    }
+
+    /// Inserts `value` at `index`, shifting the elements after it to the right
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len, "insertion index (is {}) should be <= len (is {})", index, self.len);
+
+        if self.len == self.capacity {
+            self.grow();
+        }
+
+        unsafe {
+            let gap = self.ptr.as_ptr().add(index);
+            ptr::copy(gap, gap.add(1), self.len - index);
+            ptr::write(gap, value);
+        }
+        self.len += 1;
+    }
+
+    /// Inserts clones of `values` at `index`, shifting the elements after it
+    /// to the right
+    ///
+    /// The tail is shifted once for the whole slice, rather than once per
+    /// element as with repeated single insertions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::from([1, 5]);
+    /// vec.insert_from_slice(1, &[2, 3, 4]);
+    /// assert_eq!(&*vec, &[1, 2, 3, 4, 5]);
+    /// ```
+    pub fn insert_from_slice(&mut self, index: usize, values: &[T])
+    where
+        T: Clone,
+    {
+        assert!(index <= self.len, "insertion index (is {}) should be <= len (is {})", index, self.len);
+
+        let original_len = self.len;
+        self.grow_to(original_len + values.len());
+        // Leak the tail while the gap is open so a panicking clone cannot
+        // expose uninitialized slots
+        self.len = index;
+
+        unsafe {
+            let gap = self.ptr.as_ptr().add(index);
+            ptr::copy(gap, gap.add(values.len()), original_len - index);
+            for (i, value) in values.iter().enumerate() {
+                ptr::write(gap.add(i), value.clone());
+            }
+        }
+        self.len = original_len + values.len();
+    }
+
+    /// Appends clones of the elements in `src` to the end of the vector
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends or ends past the length of
+    /// the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::from([10, 20, 30, 40]);
+    /// vec.extend_from_within(1..3);
+    /// assert_eq!(&*vec, &[10, 20, 30, 40, 20, 30]);
+    /// ```
+    pub fn extend_from_within<R: RangeBounds<usize>>(&mut self, src: R)
+    where
+        T: Clone,
+    {
+        let Range { start, end } = slice_range(src, self.len);
+        self.reserve(end - start);
+
+        // Index through the raw pointer after reserving, since growing may
+        // have moved the buffer; `len` only covers fully cloned elements, so
+        // a panicking clone leaves the vector valid
+        for i in start..end {
+            unsafe {
+                let value = (*self.ptr.as_ptr().add(i)).clone();
+                ptr::write(self.ptr.as_ptr().add(self.len), value);
+            }
+            self.len += 1;
+        }
+    }
+
+    /// Shortens the vector to its first `len` elements, dropping the rest
+    ///
+    /// Does nothing if `len` is greater than or equal to the current length.
+    /// The capacity is unchanged.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        let tail_len = self.len - len;
+        // Shorten first, so a panicking destructor cannot expose the
+        // elements being dropped
+        self.len = len;
+        unsafe {
+            let tail = self.ptr.as_ptr().add(len);
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(tail, tail_len));
+        }
+    }
+
+    /// Resizes the vector to `new_len` elements, filling any new slots with
+    /// clones of `value`
+    ///
+    /// If `new_len` is smaller than the current length, the vector is
+    /// truncated instead.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+
+        self.reserve(new_len - self.len);
+        // `len` only covers fully written elements, so a panicking clone
+        // leaves the vector valid
+        while self.len + 1 < new_len {
+            unsafe {
+                ptr::write(self.ptr.as_ptr().add(self.len), value.clone());
+            }
+            self.len += 1;
+        }
+        unsafe {
+            ptr::write(self.ptr.as_ptr().add(self.len), value);
+        }
+        self.len += 1;
+    }
+
+    /// Resizes the vector to `new_len` elements, filling any new slots with
+    /// zeroes
+    ///
+    /// This is equivalent to `resize(new_len, zero)`, but the new slots are
+    /// cleared with a single `memset`, which is much faster for large
+    /// buffers. If `new_len` is smaller than the current length, the vector
+    /// is truncated instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::from([1u8, 2]);
+    /// vec.resize_zeroed(4);
+    /// assert_eq!(&*vec, &[1, 2, 0, 0]);
+    /// ```
+    pub fn resize_zeroed(&mut self, new_len: usize)
+    where
+        T: Zeroable,
+    {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+
+        self.reserve(new_len - self.len);
+        unsafe {
+            ptr::write_bytes(self.ptr.as_ptr().add(self.len), 0, new_len - self.len);
+        }
+        self.len = new_len;
+    }
+
+    /// Replaces the elements in `range` with the items of `replace_with`,
+    /// returning the removed elements
+    ///
+    /// The replacement may be longer or shorter than the range; the elements
+    /// after the range are shifted once to make room or close the gap. The
+    /// replacement happens immediately, so the returned iterator only hands
+    /// back the removed elements, and dropping it drops any it did not yield.
+    ///
+    /// Unlike `std`'s lazy `Vec::splice`, which fills the gap from the
+    /// iterator when its `Splice<'_, I>` is dropped, this is eager and
+    /// allocates twice: `replace_with` is collected into a temporary vector
+    /// before anything is moved, and the removed range is moved into a second
+    /// one that backs the returned [`Splice`]. Collecting first means a
+    /// panicking iterator leaves the vector untouched, and the returned
+    /// iterator does not borrow the vector, which is complete and consistent
+    /// as soon as this returns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends or ends past the length of
+    /// the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::from([1, 2, 3, 4]);
+    /// let removed: Vec<i32> = vec.splice(1..3, [7, 8, 9]).collect();
+    ///
+    /// assert_eq!(removed, [2, 3]);
+    /// assert_eq!(&*vec, &[1, 7, 8, 9, 4]);
+    /// ```
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Splice<T>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let Range { start, end } = slice_range(range, self.len);
+
+        // Collect the replacement first, so a panicking iterator leaves the
+        // vector untouched
+        let mut replacement = Vector::new();
+        for item in replace_with {
+            replacement.push(item);
+        }
+
+        let removed_len = end - start;
+        let tail_len = self.len - end;
+        let new_len = self.len - removed_len + replacement.len;
+        let mut removed = Vector::with_capacity(removed_len);
+
+        self.grow_to(new_len);
+        unsafe {
+            let base = self.ptr.as_ptr();
+            ptr::copy_nonoverlapping(base.add(start), removed.ptr.as_ptr(), removed_len);
+            removed.len = removed_len;
+
+            ptr::copy(base.add(end), base.add(start + replacement.len), tail_len);
+            ptr::copy_nonoverlapping(replacement.ptr.as_ptr(), base.add(start), replacement.len);
+            // The replacement elements now belong to `self`
+            replacement.len = 0;
+        }
+        self.len = new_len;
+
+        Splice { removed, next: 0 }
+    }
+
+    /// Forces the length of the vector to `new_len`
+    ///
+    /// This is a low-level operation that does not drop or initialize any
+    /// elements. It is typically paired with [`spare_capacity_mut`] to
+    /// initialize elements in place before making them visible.
+    ///
+    /// # Safety
+    ///
+    /// - `new_len` must be less than or equal to [`capacity`].
+    /// - The elements at `old_len..new_len` must be initialized.
+    ///
+    /// [`spare_capacity_mut`]: Vector::spare_capacity_mut
+    /// [`capacity`]: Vector::capacity
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity);
+        self.len = new_len;
+    }
+
+    /// Returns the remaining spare capacity of the vector as a slice of
+    /// `MaybeUninit<T>`
+    ///
+    /// The returned slice covers the `len..capacity` region and can be used
+    /// to fill the vector with data before marking it as initialized with
+    /// [`set_len`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::with_capacity(3);
+    /// for (i, slot) in vec.spare_capacity_mut().iter_mut().enumerate() {
+    ///     slot.write(i as u32);
+    /// }
+    ///
+    /// unsafe { vec.set_len(3) };
+    /// assert_eq!(&*vec, &[0, 1, 2]);
+    /// ```
+    ///
+    /// [`set_len`]: Vector::set_len
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                self.ptr.as_ptr().add(self.len) as *mut MaybeUninit<T>,
+                self.capacity - self.len,
+            )
+        }
+    }
+
+    /// Shrinks the capacity of the vector as much as possible
+    ///
+    /// This is equivalent to [`shrink_to(0)`](Vector::shrink_to).
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
+    }
+
+    /// Shrinks the capacity of the vector to at least `min_capacity`
+    ///
+    /// The capacity never drops below `len`, and the vector is left untouched
+    /// if its capacity is already at most `min_capacity`. Shrinking an empty
+    /// vector to zero releases its buffer entirely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec: Vector<i32> = Vector::with_capacity(10);
+    /// vec.push(1);
+    /// vec.shrink_to(4);
+    /// assert_eq!(vec.capacity(), 4);
+    /// vec.shrink_to(0);
+    /// assert_eq!(vec.capacity(), 1);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let new_capacity = self.len.max(min_capacity);
+        if new_capacity >= self.capacity || mem::size_of::<T>() == 0 {
+            return;
+        }
+
+        let old_layout = array_layout::<T>(self.capacity);
+        if new_capacity == 0 {
+            unsafe {
+                self.alloc.deallocate(self.ptr.cast(), old_layout);
+            }
+            self.ptr = NonNull::dangling();
+            self.capacity = 0;
+            return;
+        }
+
+        let new_layout = array_layout::<T>(new_capacity);
+        self.ptr = unsafe { self.alloc.shrink(self.ptr.cast(), old_layout, new_layout) }
+            .unwrap_or_else(|_| alloc::handle_alloc_error(new_layout))
+            .cast();
+        self.capacity = new_capacity;
+    }
+
+    // Private method to grow the vector's capacity
+    //
+    // Capacity grows by roughly 1.5x rather than doubling, which keeps the
+    // over-allocation of large vectors down and gives the allocator a better
+    // chance of reusing freed blocks, while still keeping pushes amortized O(1).
+    fn grow(&mut self) {
+        self.grow_to(self.capacity.checked_add(1).expect("capacity overflow"));
+    }
+
+    // Private method to grow the capacity to at least `min_capacity`, using
+    // the same 1.5x growth as `grow` unless more room is needed
+    fn grow_to(&mut self, min_capacity: usize) {
+        match self.try_grow_to(min_capacity) {
+            Ok(()) => {}
+            Err(TryReserveError::CapacityOverflow) => panic!("capacity overflow"),
+            Err(TryReserveError::AllocError { layout }) => alloc::handle_alloc_error(layout),
+        }
+    }
+
+    // Private method backing `grow_to` that reports failures instead of
+    // panicking or aborting; the vector is unchanged on error
+    //
+    // A buffer may not exceed `isize::MAX` bytes. Only `min_capacity` has to
+    // fit under that limit: when the 1.5x growth would not, the capacity is
+    // clamped to the largest one that does.
+    fn try_grow_to(&mut self, min_capacity: usize) -> Result<(), TryReserveError> {
+        if min_capacity <= self.capacity {
+            return Ok(());
+        }
+        if mem::size_of::<T>() == 0 {
+            // Zero-sized elements never touch the allocator and their
+            // capacity is already `usize::MAX`
+            return Err(TryReserveError::CapacityOverflow);
+        }
+
+        let max_capacity = isize::MAX as usize / mem::size_of::<T>().max(1);
+        if min_capacity > max_capacity {
+            return Err(TryReserveError::CapacityOverflow);
+        }
+
+        let new_capacity = self
+            .capacity
+            .saturating_add(self.capacity / 2)
+            .max(MIN_NON_ZERO_CAPACITY)
+            .max(min_capacity)
+            .min(max_capacity);
+
+        let new_layout = Layout::array::<T>(new_capacity).map_err(|_| TryReserveError::CapacityOverflow)?;
+        let ptr = if self.capacity == 0 {
+            self.alloc.allocate(new_layout)
+        } else {
+            let old_layout = array_layout::<T>(self.capacity);
+            
+            unsafe {
+                self.alloc.grow(self.ptr.cast(), old_layout, new_layout)
+            }
+        };
+
+        self.ptr = ptr
+            .map_err(|_| TryReserveError::AllocError { layout: new_layout })?
+            .cast();
+        self.capacity = new_capacity;
+        Ok(())
+    }
+}
+
+impl<T, A: Allocator> Drop for Vector<T, A> {
+    fn drop(&mut self) {
+        // Drop all elements
+        for i in 0..self.len {
+            unsafe {
+                ptr::drop_in_place(self.ptr.as_ptr().add(i));
+            }
+        }
+
+        // Zero-sized elements never get a buffer, and neither does an empty
+        // vector that has not grown
+        if self.capacity == 0 || mem::size_of::<T>() == 0 {
+            return;
+        }
+
+        // Deallocate the memory
+        unsafe {
+            let layout = array_layout::<T>(self.capacity);
+            self.alloc.deallocate(self.ptr.cast(), layout);
+        }
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for Vector<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        // The iterator takes over ownership of the elements, so the vector
+        // itself only frees the buffer once the iterator is dropped
+        let end = mem::replace(&mut self.len, 0);
+        IntoIter {
+            buf: self,
+            start: 0,
+            end,
+        }
+    }
+}
+
+/// An owning iterator over the elements of a [`Vector`]
+///
+/// Any elements that have not been yielded are dropped along with the
+/// iterator.
+pub struct IntoIter<T, A: Allocator = Global> {
+    // Only `start..end` of the buffer still holds elements owned by the
+    // iterator; `buf.len` is kept at zero
+    buf: Vector<T, A>,
+    start: usize,
+    end: usize,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        let item = unsafe { ptr::read(self.buf.ptr.as_ptr().add(self.start)) };
+        self.start += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        Some(unsafe { ptr::read(self.buf.ptr.as_ptr().add(self.end)) })
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> Drop for IntoIter<T, A> {
+    fn drop(&mut self) {
+        let remaining = self.end - self.start;
+        unsafe {
+            // Dropping the slice keeps going past a panicking element, and
+            // the buffer is freed by `buf` afterwards
+            let rest = self.buf.ptr.as_ptr().add(self.start);
+            self.start = self.end;
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(rest, remaining));
+        }
+    }
+}
+
+/// An owning iterator over the elements of a [`Vector`] in `N`-element
+/// arrays
+///
+/// This is created by [`Vector::into_array_chunks`]. The remainder, and any
+/// arrays that have not been yielded, are dropped along with the iterator.
+pub struct ArrayChunks<T, const N: usize, A: Allocator = Global> {
+    iter: IntoIter<T, A>,
+}
+
+impl<T, const N: usize, A: Allocator> ArrayChunks<T, N, A> {
+    /// Returns the trailing elements that do not fill a whole array
+    ///
+    /// These are never yielded; the slice is shorter than `N`.
+    pub fn remainder(&self) -> &[T] {
+        let IntoIter { buf, start, end } = &self.iter;
+        let rest = (end - start) % N;
+        unsafe { core::slice::from_raw_parts(buf.ptr.as_ptr().add(end - rest), rest) }
+    }
+}
+
+impl<T, const N: usize, A: Allocator> Iterator for ArrayChunks<T, N, A> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter = &mut self.iter;
+        if iter.end - iter.start < N {
+            return None;
+        }
+
+        // `[T; N]` has the layout of `N` consecutive `T`s, all of which are
+        // still owned by the iterator
+        let chunk = unsafe { ptr::read(iter.buf.ptr.as_ptr().add(iter.start).cast::<[T; N]>()) };
+        iter.start += N;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.iter.end - self.iter.start) / N;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const N: usize, A: Allocator> ExactSizeIterator for ArrayChunks<T, N, A> {}
+
+impl<T, const N: usize, A: Allocator> FusedIterator for ArrayChunks<T, N, A> {}
+
+/// An iterator over the elements removed by [`Vector::splice`]
+///
+/// Any elements that have not been yielded are dropped along with the
+/// iterator.
+pub struct Splice<T> {
+    // Only `removed[next..]` is still owned by the iterator
+    removed: Vector<T>,
+    next: usize,
+}
+
+impl<T> Iterator for Splice<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.removed.len {
+            return None;
+        }
+
+        let item = unsafe { ptr::read(self.removed.ptr.as_ptr().add(self.next)) };
+        self.next += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.removed.len - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Splice<T> {}
+
+impl<T> FusedIterator for Splice<T> {}
+
+impl<T> Drop for Splice<T> {
+    fn drop(&mut self) {
+        let remaining = self.removed.len - self.next;
+        unsafe {
+            // Forget every element first, so the buffer is freed even if
+            // dropping one of the remaining elements panics
+            self.removed.len = 0;
+            let tail = self.removed.ptr.as_ptr().add(self.next);
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(tail, remaining));
+        }
+    }
+}
+
+impl<T, A: Allocator> Deref for Vector<T, A> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            core::slice::from_raw_parts(self.ptr.as_ptr(), self.len)
+        }
+    }
+}
+
+impl<T, A: Allocator> DerefMut for Vector<T, A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe {
+            core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len)
+        }
+    }
+}
+
+impl<T, A: Allocator, I: core::slice::SliceIndex<[T]>> Index<I> for Vector<T, A> {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(&**self, index)
+    }
+}
+
+impl<T, A: Allocator, I: core::slice::SliceIndex<[T]>> IndexMut<I> for Vector<T, A> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(&mut **self, index)
+    }
+}
+
+impl<T, A: Allocator> AsRef<[T]> for Vector<T, A> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, A: Allocator> AsMut<[T]> for Vector<T, A> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, A: Allocator> Borrow<[T]> for Vector<T, A> {
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, A: Allocator> BorrowMut<[T]> for Vector<T, A> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for Vector<T, A> {
+    fn clone(&self) -> Self {
+        let mut new_vec = Self::with_capacity_in(self.capacity, self.alloc.clone());
+        
+        for item in self.iter() {
+            new_vec.push(item.clone());
+        }
+        
+        new_vec
+    }
+
+    /// Overwrites `self` with a copy of `source`, reusing the existing buffer
+    ///
+    /// Elements are cloned into the slots the vectors have in common and
+    /// surplus elements are dropped. The buffer is only reallocated if it is
+    /// too small to hold `source`.
+    fn clone_from(&mut self, source: &Self) {
+        while self.len > source.len {
+            drop(self.pop());
+        }
+
+        let (common, rest) = source.split_at(self.len);
+        for (item, source_item) in self.iter_mut().zip(common) {
+            item.clone_from(source_item);
+        }
+
+        self.grow_to(source.len);
+        for item in rest {
+            self.push(item.clone());
+        }
+    }
+}
+
+impl<T: Clone, A: Allocator, B: Allocator> Vector<Vector<T, B>, A> {
+    /// Flattens a vector of vectors into a single vector, cloning each
+    /// element
+    ///
+    /// The output is allocated once with the total length up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let nested = Vector::from([Vector::from([1, 2]), Vector::from([3])]);
+    /// assert_eq!(&*nested.concat(), &[1, 2, 3]);
+    /// ```
+    pub fn concat(&self) -> Vector<T> {
+        let total = self.iter().map(|inner| inner.len()).sum();
+        let mut vec = Vector::with_capacity(total);
+        for inner in self.iter() {
+            for item in inner.iter() {
+                vec.push(item.clone());
+            }
+        }
+        vec
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T> {
+    fn from(array: [T; N]) -> Self {
+        let mut vec = Self::with_capacity(N);
+        // The elements are moved into the vector, so the array itself must
+        // not be dropped
+        let array = mem::ManuallyDrop::new(array);
+
+        unsafe {
+            ptr::copy_nonoverlapping(array.as_ptr(), vec.ptr.as_ptr(), N);
+        }
+        vec.len = N;
+
+        vec
+    }
+}
+
+impl<T: Clone> From<&[T]> for Vector<T> {
+    fn from(slice: &[T]) -> Self {
+        let mut vec = Self::with_capacity(slice.len());
+        for item in slice {
+            vec.push(item.clone());
+        }
+        vec
+    }
+}
+
+impl<T> FromIterator<T> for Vector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Vector::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for Vector<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        // Reserve the lower bound up front, so iterators of known length
+        // allocate once; anything beyond it grows as usual
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+/// Takes over the buffer of a `Vec` without copying its elements
+///
+/// For element types with a non-zero size, the length and capacity are
+/// preserved exactly: both types allocate from the global allocator with
+/// `Layout::array::<T>(capacity)`, so the buffer can be reinterpreted as is.
+/// A `Vec` of zero-sized elements has no buffer; only its length carries
+/// over, and the vector's capacity is `usize::MAX` like any other vector of
+/// zero-sized elements.
+///
+/// Neither case can fail, so `Vector::try_from(vec)` is available through
+/// the standard blanket impl, with [`Infallible`] as its error.
+///
+/// [`Infallible`]: core::convert::Infallible
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut vec = mem::ManuallyDrop::new(vec);
+        if mem::size_of::<T>() == 0 {
+            // There is no buffer to take over; only the length carries over
+            let mut zst = Self::new();
+            zst.len = vec.len();
+            return zst;
+        }
+
+        // Both types keep their elements in a single buffer obtained from the
+        // global allocator with `Layout::array::<T>(capacity)`, so ownership
+        // of the buffer can be handed over without touching the elements
+        unsafe { Self::from_raw_parts(vec.as_mut_ptr(), vec.len(), vec.capacity()) }
+    }
+}
+
+impl<T> From<Vector<T>> for Vec<T> {
+    fn from(vec: Vector<T>) -> Self {
+        // For zero-sized elements the pointer is dangling and the capacity
+        // is `usize::MAX`, which `Vec` accepts as a zero-byte allocation
+        let (ptr, len, capacity) = vec.into_raw_parts();
+        unsafe { Vec::from_raw_parts(ptr, len, capacity) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::allocator::AllocError;
+    use std::cell::Cell;
+
+    #[derive(Default)]
+    struct CountingAllocator {
+        allocs: Cell<usize>,
+        deallocs: Cell<usize>,
+    }
+
+    unsafe impl Allocator for CountingAllocator {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+            self.allocs.set(self.allocs.get() + 1);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.deallocs.set(self.deallocs.get() + 1);
+            Global.deallocate(ptr, layout)
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let vec: Vector<i32> = Vector::new();
+        assert_eq!(vec.len(), 0);
+        assert_eq!(vec.capacity(), 0);
+    }
+
+    #[test]
+    fn test_with_capacity() {
+        let vec: Vector<i32> = Vector::with_capacity(10);
+        assert_eq!(vec.len(), 0);
+        assert_eq!(vec.capacity(), 10);
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut vec = Vector::new();
+        vec.push(1);
+        vec.push(2);
+        vec.push(3);
+        
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec.pop(), Some(3));
+        assert_eq!(vec.len(), 2);
+        assert_eq!(vec.pop(), Some(2));
+        assert_eq!(vec.pop(), Some(1));
+        assert_eq!(vec.pop(), None);
+        assert_eq!(vec.len(), 0);
+    }
+
+    #[test]
     fn test_get() {
         let mut vec = Vector::new();
         vec.push(1);
-        vec.push(2);
-        
-        assert_eq!(vec.get(0), Some(&1));
-        assert_eq!(vec.get(1), Some(&2));
-        assert_eq!(vec.get(2), None);
+        vec.push(2);
+        
+        assert_eq!(vec.get(0), Some(&1));
+        assert_eq!(vec.get(1), Some(&2));
+        assert_eq!(vec.get(2), None);
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut vec = Vector::new();
+        vec.push(1);
+        vec.push(2);
+        
+        assert_eq!(vec[0], 1);
+        assert_eq!(vec[1], 2);
+    }
+
+    #[test]
+    fn test_get_unchecked() {
+        let mut vec = Vector::new();
+        vec.push(1);
+        vec.push(2);
+        vec.push(3);
+
+        for i in 0..vec.len() {
+            unsafe {
+                *vec.get_unchecked_mut(i) *= 10;
+            }
+        }
+
+        unsafe {
+            assert_eq!(*vec.get_unchecked(0), 10);
+            assert_eq!(*vec.get_unchecked(1), 20);
+            assert_eq!(*vec.get_unchecked(2), 30);
+        }
+    }
+
+    #[test]
+    fn test_into_filter() {
+        use std::rc::Rc;
+
+        struct DropCounter {
+            value: i32,
+            drops: Rc<Cell<usize>>,
+        }
+
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                self.drops.set(self.drops.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let mut vec = Vector::new();
+        for value in 1..=4 {
+            vec.push(DropCounter { value, drops: Rc::clone(&drops) });
+        }
+
+        let evens = vec.into_filter(|item| item.value % 2 == 0);
+        assert_eq!(drops.get(), 2);
+        assert_eq!(evens.len(), 2);
+        assert_eq!(evens[0].value, 2);
+        assert_eq!(evens[1].value, 4);
+
+        drop(evens);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn test_set_len_spare_capacity() {
+        let mut vec: Vector<u32> = Vector::with_capacity(4);
+        vec.push(1);
+
+        let spare = vec.spare_capacity_mut();
+        assert_eq!(spare.len(), 3);
+        for (i, slot) in spare.iter_mut().enumerate() {
+            slot.write((i as u32 + 2) * 10);
+        }
+
+        unsafe {
+            vec.set_len(4);
+        }
+
+        assert_eq!(vec.len(), 4);
+        assert_eq!(&*vec, &[1, 20, 30, 40]);
+        assert!(vec.spare_capacity_mut().is_empty());
+    }
+
+    #[test]
+    fn test_push_within_capacity() {
+        let mut vec = Vector::with_capacity(2);
+        assert_eq!(vec.push_within_capacity(String::from("a")), Ok(()));
+        assert_eq!(vec.push_within_capacity(String::from("b")), Ok(()));
+
+        assert_eq!(
+            vec.push_within_capacity(String::from("c")),
+            Err(String::from("c"))
+        );
+        assert_eq!(vec.len(), 2);
+        assert_eq!(vec.capacity(), 2);
+
+        let mut empty: Vector<i32> = Vector::new();
+        assert_eq!(empty.push_within_capacity(1), Err(1));
+        assert_eq!(empty.capacity(), 0);
+    }
+
+    #[test]
+    fn test_partition_point() {
+        let mut vec = Vector::new();
+        vec.push(2);
+        vec.push(4);
+        vec.push(6);
+        vec.push(8);
+
+        assert_eq!(vec.partition_point(|&x| x < 5), 2);
+        assert_eq!(vec.partition_point(|&x| x < 100), 4);
+        assert_eq!(vec.partition_point(|&x| x < 0), 0);
+
+        let empty: Vector<i32> = Vector::new();
+        assert_eq!(empty.partition_point(|&x| x < 5), 0);
+    }
+
+    #[test]
+    fn test_growth_factor() {
+        let mut vec = Vector::new();
+        vec.push(0u8);
+        assert_eq!(vec.capacity(), 4);
+
+        // A few dozen reallocations cover the 1.5x step well past the
+        // minimum capacity without pushing millions of elements
+        let mut capacity = vec.capacity();
+        let mut reallocations = 0;
+        while reallocations < 24 {
+            vec.push(vec.len() as u8);
+            if vec.capacity() != capacity {
+                // Each step grows by roughly half the previous capacity
+                assert_eq!(vec.capacity(), capacity + capacity / 2);
+                assert_eq!(vec.len(), capacity + 1);
+                capacity = vec.capacity();
+                reallocations += 1;
+            }
+        }
+
+        // 24 steps of 1.5x from 4 stay under 100k elements
+        assert!(vec.len() < 100_000);
+        assert!(vec.capacity() >= vec.len());
+    }
+
+    #[test]
+    fn test_growth_peak_capacity_below_doubling() {
+        const LEN: usize = 10_000_000;
+
+        // Walk the same capacity steps 10M pushes would take, one
+        // reallocation at a time, without writing any elements
+        let mut vec: Vector<u8> = Vector::new();
+        while vec.capacity() < LEN {
+            vec.reserve(vec.capacity() + 1);
+        }
+        let grown = vec.capacity();
+
+        let mut doubled = MIN_NON_ZERO_CAPACITY;
+        while doubled < LEN {
+            doubled *= 2;
+        }
+
+        assert!(grown >= LEN);
+        assert!(grown < doubled, "1.5x peak {} should be below 2x peak {}", grown, doubled);
+    }
+
+    #[test]
+    fn test_custom_allocator() {
+        let counter = CountingAllocator::default();
+        {
+            let mut vec = Vector::new_in(&counter);
+            for i in 0..100 {
+                vec.push(i.to_string());
+            }
+            assert_eq!(vec[42], "42");
+            assert!(counter.allocs.get() > 1);
+
+            let other: Vector<u64, _> = Vector::with_capacity_in(8, &counter);
+            assert_eq!(other.capacity(), 8);
+        }
+
+        assert_eq!(counter.allocs.get(), counter.deallocs.get());
+
+        // An empty vector never touches the allocator
+        drop(Vector::<i32, _>::new_in(&counter));
+        assert_eq!(counter.allocs.get(), counter.deallocs.get());
+    }
+
+    #[test]
+    fn test_zero_sized_never_allocates() {
+        use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Unit;
+
+        impl Drop for Unit {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Relaxed);
+            }
+        }
+
+        let counter = CountingAllocator::default();
+        {
+            let mut vec = Vector::new_in(&counter);
+            assert_eq!(vec.capacity(), usize::MAX);
+            for _ in 0..100 {
+                vec.push(Unit);
+            }
+            assert_eq!(vec.len(), 100);
+            assert!(vec.pop().is_some());
+            assert_eq!(DROPS.load(Relaxed), 1);
+
+            vec.reserve(1000);
+            vec.shrink_to_fit();
+            assert_eq!(vec.capacity(), usize::MAX);
+            assert_eq!(vec.try_reserve(usize::MAX), Err(TryReserveError::CapacityOverflow));
+
+            let other: Vector<(), _> = Vector::with_capacity_in(8, &counter);
+            assert_eq!(other.capacity(), usize::MAX);
+        }
+
+        assert_eq!(DROPS.load(Relaxed), 100);
+        assert_eq!(counter.allocs.get(), 0);
+        assert_eq!(counter.deallocs.get(), 0);
+    }
+
+    #[test]
+    fn test_from_array_and_slice() {
+        let vec = Vector::from([1, 2, 3]);
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec.capacity(), 3);
+        assert_eq!(&*vec, &[1, 2, 3]);
+
+        let strings = Vector::from([String::from("a"), String::from("b")]);
+        assert_eq!(&*strings, &["a", "b"]);
+
+        let empty: Vector<String> = Vector::from([]);
+        assert!(empty.is_empty());
+
+        let source = [String::from("x"), String::from("y"), String::from("z")];
+        let cloned = Vector::from(&source[1..]);
+        assert_eq!(&*cloned, &["y", "z"]);
+        assert_eq!(source.len(), 3);
+    }
+
+    #[test]
+    fn test_vec_round_trip() {
+        let mut std_vec = Vec::with_capacity(10);
+        std_vec.push(String::from("one"));
+        std_vec.push(String::from("two"));
+
+        let mut vec = Vector::from(std_vec);
+        assert_eq!(vec.len(), 2);
+        assert_eq!(vec.capacity(), 10);
+        vec.push(String::from("three"));
+        assert_eq!(&*vec, &["one", "two", "three"]);
+
+        let back: Vec<String> = vec.into();
+        assert_eq!(back, vec!["one", "two", "three"]);
+        assert_eq!(back.capacity(), 10);
+
+        // Empty vectors without a buffer convert both ways too
+        let empty = Vector::from(Vec::<String>::new());
+        assert_eq!(empty.capacity(), 0);
+        let empty: Vec<String> = empty.into();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_vec_round_trip_zero_sized() {
+        use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Unit;
+
+        impl Drop for Unit {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Relaxed);
+            }
+        }
+
+        let mut vec = Vector::from(vec![(), (), ()]);
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec.capacity(), usize::MAX);
+        vec.push(());
+        let back: Vec<()> = vec.into();
+        assert_eq!(back.len(), 4);
+
+        // Each element is dropped exactly once across both conversions
+        let units: Vec<Unit> = (0..5).map(|_| Unit).collect();
+        let vec = Vector::from(units);
+        assert_eq!(vec.len(), 5);
+        let back: Vec<Unit> = vec.into();
+        assert_eq!(DROPS.load(Relaxed), 0);
+        drop(back);
+        assert_eq!(DROPS.load(Relaxed), 5);
+    }
+
+    #[test]
+    fn test_first_last() {
+        let mut vec: Vector<i32> = Vector::new();
+        assert_eq!(vec.first(), None);
+        assert_eq!(vec.last(), None);
+        assert_eq!(vec.first_mut(), None);
+        assert_eq!(vec.last_mut(), None);
+
+        vec.push(1);
+        assert_eq!(vec.first(), Some(&1));
+        assert_eq!(vec.last(), Some(&1));
+
+        vec.push(2);
+        vec.push(3);
+        *vec.first_mut().unwrap() = 10;
+        *vec.last_mut().unwrap() = 30;
+        assert_eq!(vec.first(), Some(&10));
+        assert_eq!(vec.last(), Some(&30));
+        assert_eq!(&*vec, &[10, 2, 30]);
+    }
+
+    #[test]
+    fn test_iter_and_iter_mut() {
+        let mut vec: Vector<i32> = Vector::from([1, 2, 3]);
+        assert_eq!(vec.iter().collect::<Vec<_>>(), [&1, &2, &3]);
+
+        for x in vec.iter_mut() {
+            *x *= 10;
+        }
+        assert_eq!(vec.iter().copied().collect::<Vec<_>>(), [10, 20, 30]);
+        assert_eq!(vec.iter().next_back(), Some(&30));
+
+        let empty: Vector<i32> = Vector::new();
+        assert_eq!(empty.iter().next(), None);
+    }
+
+    #[test]
+    fn test_concat() {
+        let nested = Vector::from([
+            Vector::from([1, 2]),
+            Vector::from([3]),
+            Vector::new(),
+            Vector::from([4, 5, 6]),
+        ]);
+        let flat = nested.concat();
+        assert_eq!(&*flat, &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(flat.capacity(), 6);
+
+        let empty: Vector<Vector<i32>> = Vector::new();
+        assert!(empty.concat().is_empty());
+    }
+
+    #[test]
+    fn test_windows_and_chunks() {
+        let mut vec = Vector::from([1, 2, 3, 4, 5, 6, 7]);
+
+        let windows: Vec<&[i32]> = vec.windows(2).collect();
+        assert_eq!(windows.len(), 6);
+        assert_eq!(windows[0], &[1, 2]);
+        assert_eq!(windows[5], &[6, 7]);
+        assert_eq!(vec.windows(8).count(), 0);
+
+        let chunks: Vec<&[i32]> = vec.chunks(3).collect();
+        assert_eq!(chunks, [&[1, 2, 3][..], &[4, 5, 6], &[7]]);
+
+        let mut exact = vec.chunks_exact(3);
+        assert_eq!(exact.next(), Some(&[1, 2, 3][..]));
+        assert_eq!(exact.next(), Some(&[4, 5, 6][..]));
+        assert_eq!(exact.next(), None);
+        assert_eq!(exact.remainder(), &[7]);
+
+        for chunk in vec.chunks_mut(3) {
+            chunk.reverse();
+        }
+        assert_eq!(&*vec, &[3, 2, 1, 6, 5, 4, 7]);
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut vec = Vector::from([1, 2, 3, 4]);
+        vec.swap(0, 3);
+        assert_eq!(&*vec, &[4, 2, 3, 1]);
+
+        vec.swap(1, 1);
+        assert_eq!(&*vec, &[4, 2, 3, 1]);
+    }
+
+    #[test]
+    fn test_swap_strings() {
+        let mut vec = Vector::from([String::from("a"), String::from("b"), String::from("c")]);
+        vec.swap(2, 0);
+        assert_eq!(&*vec, &["c", "b", "a"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_swap_out_of_bounds() {
+        let mut vec = Vector::from([1, 2]);
+        vec.swap(0, 2);
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut vec = Vector::from([1, 1, 2, 3, 3, 3, 1, 4, 4]);
+        vec.dedup();
+        assert_eq!(&*vec, &[1, 2, 3, 1, 4]);
+
+        let mut empty: Vector<i32> = Vector::new();
+        empty.dedup();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_dedup_by_case_insensitive() {
+        let mut vec: Vector<String> = ["foo", "FOO", "Foo", "bar", "Bar", "foo"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .into();
+        vec.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        assert_eq!(&*vec, &["foo", "bar", "foo"]);
+    }
+
+    #[test]
+    fn test_dedup_by_drops_once() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let mut vec = Vector::new();
+        for _ in 0..5 {
+            vec.push(Rc::clone(&tracker));
+        }
+        assert_eq!(Rc::strong_count(&tracker), 6);
+
+        // Every element is "equal", so all but the first are dropped
+        vec.dedup_by(|_, _| true);
+        assert_eq!(vec.len(), 1);
+        assert_eq!(Rc::strong_count(&tracker), 2);
+
+        drop(vec);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    fn test_splice_longer() {
+        let mut vec = Vector::from([1, 2, 3, 4, 5]);
+        let removed: Vec<i32> = vec.splice(1..3, [10, 20, 30]).collect();
+        assert_eq!(removed, [2, 3]);
+        assert_eq!(&*vec, &[1, 10, 20, 30, 4, 5]);
+    }
+
+    #[test]
+    fn test_splice_shorter() {
+        let mut vec = Vector::from([1, 2, 3, 4, 5]);
+        let removed: Vec<i32> = vec.splice(1..4, [10, 20]).collect();
+        assert_eq!(removed, [2, 3, 4]);
+        assert_eq!(&*vec, &[1, 10, 20, 5]);
+    }
+
+    #[test]
+    fn test_splice_bounds() {
+        let mut vec = Vector::from([1, 2, 3]);
+        assert_eq!(vec.splice(.., []).count(), 3);
+        assert!(vec.is_empty());
+
+        vec.splice(0..0, [4, 5]);
+        vec.splice(2.., [6]);
+        vec.splice(..=0, [3]);
+        assert_eq!(&*vec, &[3, 5, 6]);
+    }
+
+    #[test]
+    fn test_splice_drops_unyielded() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let mut vec = Vector::new();
+        for _ in 0..4 {
+            vec.push(Rc::clone(&tracker));
+        }
+
+        let mut removed = vec.splice(0..3, [Rc::clone(&tracker)]);
+        let first = removed.next();
+        assert_eq!(removed.len(), 2);
+        drop(removed);
+        drop(first);
+
+        assert_eq!(vec.len(), 2);
+        assert_eq!(Rc::strong_count(&tracker), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_splice_out_of_bounds() {
+        let mut vec = Vector::from([1, 2, 3]);
+        vec.splice(2..4, [0]);
+    }
+
+    #[test]
+    fn test_pop_if() {
+        let mut vec = Vector::from([1, 2, 3]);
+        assert_eq!(vec.pop_if(|x| *x == 3), Some(3));
+
+        // The predicate can modify the element even when it is kept
+        assert_eq!(
+            vec.pop_if(|x| {
+                *x *= 10;
+                false
+            }),
+            None
+        );
+        assert_eq!(&*vec, &[1, 20]);
+
+        let mut empty: Vector<i32> = Vector::new();
+        assert_eq!(empty.pop_if(|_| true), None);
+    }
+
+    #[test]
+    fn test_clone_from() {
+        let source = Vector::from([String::from("a"), String::from("b")]);
+
+        // A larger target keeps its buffer and drops the surplus
+        let mut target: Vector<String> = Vector::with_capacity(8);
+        for i in 0..5 {
+            target.push(i.to_string());
+        }
+        target.clone_from(&source);
+        assert_eq!(&*target, &["a", "b"]);
+        assert_eq!(target.capacity(), 8);
+
+        // A preallocated, shorter target fills its spare slots in place
+        let mut target: Vector<String> = Vector::with_capacity(8);
+        target.clone_from(&source);
+        assert_eq!(&*target, &["a", "b"]);
+        assert_eq!(target.capacity(), 8);
+
+        // A target that is too small grows
+        let mut target: Vector<String> = Vector::new();
+        target.clone_from(&source);
+        assert_eq!(&*target, &["a", "b"]);
+        assert!(target.capacity() >= 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn test_with_capacity_overflow() {
+        let _vec: Vector<u64> = Vector::with_capacity(usize::MAX / 4);
+    }
+
+    #[test]
+    fn test_insert_from_slice() {
+        let mut vec = Vector::from([1, 2, 6, 7]);
+        vec.insert_from_slice(2, &[3, 4, 5]);
+        assert_eq!(&*vec, &[1, 2, 3, 4, 5, 6, 7]);
+
+        vec.insert_from_slice(0, &[0]);
+        vec.insert_from_slice(vec.len(), &[8, 9]);
+        vec.insert_from_slice(3, &[]);
+        assert_eq!(&*vec, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let mut strings: Vector<String> = Vector::new();
+        strings.insert_from_slice(0, &[String::from("a"), String::from("b")]);
+        assert_eq!(&*strings, &["a", "b"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index")]
+    fn test_insert_from_slice_out_of_bounds() {
+        let mut vec = Vector::from([1, 2]);
+        vec.insert_from_slice(3, &[0]);
+    }
+
+    #[test]
+    fn test_as_ptr() {
+        let mut vec = Vector::from([1, 2, 3]);
+
+        let ptr = vec.as_ptr();
+        let read: Vec<i32> = (0..vec.len()).map(|i| unsafe { *ptr.add(i) }).collect();
+        assert_eq!(read, [1, 2, 3]);
+
+        let ptr = vec.as_mut_ptr();
+        unsafe {
+            *ptr.add(1) = 20;
+        }
+        assert_eq!(&*vec, &[1, 20, 3]);
+
+        let empty: Vector<u64> = Vector::new();
+        assert!(!empty.as_ptr().is_null());
+        assert!(empty.as_ptr().is_aligned());
+    }
+
+    #[test]
+    fn test_shrink_to() {
+        let mut vec: Vector<i32> = Vector::with_capacity(100);
+        for i in 0..10 {
+            vec.push(i);
+        }
+
+        vec.shrink_to(20);
+        assert_eq!(vec.capacity(), 20);
+        assert_eq!(&*vec, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        // Requests above the current capacity are ignored
+        vec.shrink_to(50);
+        assert_eq!(vec.capacity(), 20);
+
+        // The capacity never drops below the length
+        vec.shrink_to(5);
+        assert_eq!(vec.capacity(), 10);
+        assert_eq!(&*vec, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        while vec.pop().is_some() {}
+        vec.shrink_to_fit();
+        assert_eq!(vec.capacity(), 0);
+        vec.push(1);
+        assert_eq!(&*vec, &[1]);
     }
 
     #[test]
-    fn test_indexing() {
+    #[cfg(feature = "std")]
+    fn test_unique() {
+        let vec = Vector::from([1, 2, 1, 3, 2, 4]);
+        assert_eq!(&*vec.unique(), &[1, 2, 3, 4]);
+        assert_eq!(&*vec, &[1, 2, 1, 3, 2, 4]);
+
+        let words = Vector::from(["b".to_string(), "a".to_string(), "b".to_string()]);
+        assert_eq!(&*words.unique(), &["b", "a"]);
+        assert!(Vector::<i32>::new().unique().is_empty());
+    }
+
+    #[test]
+    fn test_retain_mut() {
+        let mut vec = Vector::from([1, -2, 3, -4, 5]);
+        vec.retain_mut(|x| {
+            if *x < 0 {
+                return false;
+            }
+            *x *= 2;
+            true
+        });
+        assert_eq!(&*vec, &[2, 6, 10]);
+
+        vec.retain(|&x| x != 6);
+        assert_eq!(&*vec, &[2, 10]);
+    }
+
+    #[test]
+    fn test_retain_mut_drops_rejected_once() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let mut vec: Vector<Rc<()>> = (0..6).map(|_| Rc::clone(&tracker)).collect();
+        let mut i = 0;
+        vec.retain_mut(|_| {
+            i += 1;
+            i % 2 == 0
+        });
+
+        assert_eq!(vec.len(), 3);
+        assert_eq!(Rc::strong_count(&tracker), 4);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let vec = Vector::from(["a".to_string(), "b".to_string(), "c".to_string()]);
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next().as_deref(), Some("a"));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next().as_deref(), Some("b"));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next().as_deref(), Some("c"));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+
+        let collected: Vec<i32> = Vector::from([1, 2, 3]).into_iter().collect();
+        assert_eq!(collected, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_into_iter_drops_remaining() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let vec: Vector<Rc<()>> = (0..4).map(|_| Rc::clone(&tracker)).collect();
+        let mut iter = vec.into_iter();
+        drop(iter.next());
+        assert_eq!(Rc::strong_count(&tracker), 4);
+
+        drop(iter);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut vec = Vector::new();
+        vec.insert(0, 2);
+        vec.insert(0, 0);
+        vec.insert(1, 1);
+        vec.insert(3, 3);
+        assert_eq!(&*vec, &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+    fn test_insert_out_of_bounds() {
+        let mut vec = Vector::from([1]);
+        vec.insert(2, 2);
+    }
+
+    #[test]
+    fn test_binary_insert() {
         let mut vec = Vector::new();
+        for value in [7, 3, 9, 1, 4, 8, 2, 6, 0, 5, 3] {
+            let index = vec.binary_insert(value);
+            assert_eq!(vec[index], value);
+            assert!(vec.windows(2).all(|w| w[0] <= w[1]));
+        }
+        assert_eq!(&*vec, &[0, 1, 2, 3, 3, 4, 5, 6, 7, 8, 9]);
+
+        // Equal keys are placed after existing ones, keeping insertion order
+        let mut pairs = Vector::new();
+        for pair in [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')] {
+            pairs.binary_insert_by(pair, |x, y| x.0.cmp(&y.0));
+        }
+        assert_eq!(&*pairs, &[(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+    }
+
+    #[test]
+    fn test_from_iter_reserves_lower_bound() {
+        let vec: Vector<i32> = (0..1000).collect();
+        assert_eq!(vec.len(), 1000);
+        assert_eq!(vec.capacity(), 1000);
+        assert!(vec.iter().copied().eq(0..1000));
+
+        // Without a useful lower bound the vector grows as it goes
+        let evens: Vector<i32> = (0..1000).filter(|x| x % 2 == 0).collect();
+        assert_eq!(evens.len(), 500);
+        assert!(evens.capacity() >= 500);
+
+        let mut vec = Vector::from([1, 2]);
+        vec.extend(3..=10);
+        assert_eq!(vec.capacity(), 10);
+        assert_eq!(&*vec, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn test_reserve() {
+        let mut vec: Vector<i32> = Vector::new();
+        vec.reserve(10);
+        assert_eq!(vec.capacity(), 10);
+
+        // Already enough room
         vec.push(1);
-        vec.push(2);
-        
-        assert_eq!(vec[0], 1);
-        assert_eq!(vec[1], 2);
+        vec.reserve(9);
+        assert_eq!(vec.capacity(), 10);
+    }
+
+    #[test]
+    fn test_into_iter_double_ended() {
+        let vec = Vector::from([1, 2, 3, 4, 5]);
+        assert!(vec.into_iter().rev().eq([5, 4, 3, 2, 1]));
+
+        let vec: Vector<String> = (0..5).map(|i| i.to_string()).collect();
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.next().as_deref(), Some("0"));
+        assert_eq!(iter.next_back().as_deref(), Some("4"));
+        assert_eq!(iter.next().as_deref(), Some("1"));
+        assert_eq!(iter.next_back().as_deref(), Some("3"));
+        assert_eq!(iter.len(), 1);
+        // The untouched middle element is dropped with the iterator
+        drop(iter);
+
+        let vec: Vector<String> = (0..3).map(|i| i.to_string()).collect();
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.next_back().as_deref(), Some("2"));
+        assert_eq!(iter.next().as_deref(), Some("0"));
+        assert_eq!(iter.next_back().as_deref(), Some("1"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_retain_count() {
+        let mut vec: Vector<i32> = (0..10).collect();
+        assert_eq!(vec.retain_count(|&x| x % 3 != 0 || x == 0), 3);
+        assert_eq!(&*vec, &[0, 1, 2, 4, 5, 7, 8]);
+
+        assert_eq!(vec.retain_count(|_| true), 0);
+        assert_eq!(vec.retain_count(|_| false), 7);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut even = Vector::from(["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()]);
+        even.reverse();
+        assert_eq!(&*even, &["d", "c", "b", "a"]);
+
+        let mut odd = Vector::from([1, 2, 3, 4, 5]);
+        odd.reverse();
+        assert_eq!(&*odd, &[5, 4, 3, 2, 1]);
+
+        let mut empty: Vector<i32> = Vector::new();
+        empty.reverse();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_try_from_vec() {
+        // Generic code that only asks for `TryFrom` can take a `Vec`
+        fn convert<T, U: TryFrom<Vec<T>>>(vec: Vec<T>) -> Result<U, U::Error> {
+            U::try_from(vec)
+        }
+
+        let vec: Vector<i32> = convert(vec![1, 2, 3]).unwrap();
+        assert_eq!(&*vec, &[1, 2, 3]);
+        assert_eq!(Vec::from(vec), [1, 2, 3]);
+
+        let mut source = Vec::with_capacity(10);
+        source.push("a".to_string());
+        let capacity = source.capacity();
+        let ptr = source.as_ptr();
+
+        let vec: Vector<String> = convert(source).unwrap();
+        assert_eq!(vec.capacity(), capacity);
+        assert_eq!(vec.as_ptr(), ptr);
+        assert_eq!(&*vec, &["a"]);
+
+        // Zero-sized elements keep their length; there is no buffer to take
+        let mut source = Vec::with_capacity(10);
+        source.extend([(), ()]);
+        let vec: Vector<()> = convert(source).unwrap();
+        assert_eq!(vec.len(), 2);
+        assert_eq!(vec.capacity(), usize::MAX);
+    }
+
+    #[test]
+    fn test_leak() {
+        let slice: &'static mut [String] = Vector::from(["a".to_string(), "b".to_string()]).leak();
+        slice[1].push('c');
+        slice.swap(0, 1);
+        assert_eq!(slice, &["bc", "a"]);
+    }
+
+    #[test]
+    fn test_split_first_last() {
+        let mut vec = Vector::from([1, 2, 3]);
+        assert_eq!(vec.split_first(), Some((&1, &[2, 3][..])));
+        assert_eq!(vec.split_last(), Some((&3, &[1, 2][..])));
+
+        if let Some((first, rest)) = vec.split_first_mut() {
+            *first += 10;
+            rest[0] += 20;
+        }
+        if let Some((last, rest)) = vec.split_last_mut() {
+            *last += 30;
+            rest[0] += 40;
+        }
+        assert_eq!(&*vec, &[51, 22, 33]);
+
+        let mut empty: Vector<i32> = Vector::new();
+        assert_eq!(empty.split_first(), None);
+        assert_eq!(empty.split_last(), None);
+        assert!(empty.split_first_mut().is_none());
+        assert!(empty.split_last_mut().is_none());
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_as_ref_and_borrow() {
+        fn checksum(bytes: impl AsRef<[u8]>) -> u32 {
+            bytes.as_ref().iter().map(|&b| b as u32).sum()
+        }
+
+        fn zero(mut bytes: impl AsMut<[u8]>) {
+            bytes.as_mut().fill(0);
+        }
+
+        let mut vec = Vector::from([1u8, 2, 3]);
+        assert_eq!(checksum(&vec), 6);
+        zero(&mut vec);
+        assert_eq!(checksum(vec), 0);
+
+        let mut vec = Vector::from([3, 1, 2]);
+        <Vector<i32> as BorrowMut<[i32]>>::borrow_mut(&mut vec).sort();
+        let slice: &[i32] = vec.borrow();
+        assert_eq!(slice, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_align_to_simd_sum() {
+        #[repr(C, align(16))]
+        #[derive(Clone, Copy)]
+        struct F32x4([f32; 4]);
+
+        let vec: Vector<f32> = (0..1003).map(|i| (i % 7) as f32).collect();
+        let scalar: f32 = vec.iter().sum();
+
+        let (prefix, middle, suffix) = unsafe { vec.align_to::<F32x4>() };
+        assert!(prefix.len() < 4 && suffix.len() < 4);
+        assert_eq!(prefix.len() + middle.len() * 4 + suffix.len(), 1003);
+
+        let mut lanes = [0.0f32; 4];
+        for chunk in middle {
+            for (lane, value) in lanes.iter_mut().zip(chunk.0) {
+                *lane += value;
+            }
+        }
+        let simd: f32 = prefix.iter().sum::<f32>() + lanes.iter().sum::<f32>() + suffix.iter().sum::<f32>();
+        assert_eq!(simd, scalar);
+
+        let mut vec = vec;
+        let (_, middle, _) = unsafe { vec.align_to_mut::<F32x4>() };
+        for chunk in middle.iter_mut() {
+            chunk.0 = [0.0; 4];
+        }
+        assert!(vec.iter().sum::<f32>() < scalar);
+    }
+
+    #[test]
+    fn test_try_push_allocation_failure() {
+        // Allocates normally until `budget` allocations have been made
+        struct FailingAllocator {
+            budget: Cell<usize>,
+        }
+
+        unsafe impl Allocator for FailingAllocator {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+                match self.budget.get() {
+                    0 => Err(AllocError),
+                    n => {
+                        self.budget.set(n - 1);
+                        Global.allocate(layout)
+                    }
+                }
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                Global.deallocate(ptr, layout)
+            }
+        }
+
+        let allocator = FailingAllocator { budget: Cell::new(1) };
+        let mut vec = Vector::new_in(&allocator);
+        for i in 0..4 {
+            vec.try_push(i).unwrap();
+        }
+        assert_eq!(vec.capacity(), 4);
+
+        let err = vec.try_push(4).unwrap_err();
+        assert_eq!(err, TryReserveError::AllocError { layout: Layout::array::<i32>(6).unwrap() });
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec.capacity(), 4);
+        assert_eq!(&*vec, &[0, 1, 2, 3]);
+
+        assert_eq!(vec.try_reserve(usize::MAX), Err(TryReserveError::CapacityOverflow));
+        assert_eq!(vec.try_reserve(isize::MAX as usize), Err(TryReserveError::CapacityOverflow));
+        assert_eq!(err.to_string(), "memory allocation of 24 bytes failed");
+    }
+
+    #[test]
+    fn test_extend_from_within() {
+        let mut vec = Vector::from([10, 20, 30, 40]);
+        vec.extend_from_within(1..3);
+        assert_eq!(&*vec, &[10, 20, 30, 40, 20, 30]);
+
+        // Forces a reallocation while cloning from the old contents
+        let mut words = Vector::with_capacity(2);
+        words.push("a".to_string());
+        words.push("b".to_string());
+        words.extend_from_within(..);
+        words.extend_from_within(3..=3);
+        assert_eq!(&*words, &["a", "b", "a", "b", "b"]);
+
+        words.extend_from_within(2..2);
+        assert_eq!(words.len(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end 5 out of bounds for length 4")]
+    fn test_extend_from_within_out_of_bounds() {
+        let mut vec = Vector::from([1, 2, 3, 4]);
+        vec.extend_from_within(2..5);
+    }
+
+    #[test]
+    fn test_truncate_and_resize() {
+        let mut vec = Vector::from(["a".to_string(), "b".to_string(), "c".to_string()]);
+        vec.truncate(5);
+        assert_eq!(vec.len(), 3);
+        vec.truncate(1);
+        assert_eq!(&*vec, &["a"]);
+        assert_eq!(vec.capacity(), 3);
+
+        vec.resize(4, "x".to_string());
+        assert_eq!(&*vec, &["a", "x", "x", "x"]);
+        vec.resize(2, "y".to_string());
+        assert_eq!(&*vec, &["a", "x"]);
+    }
+
+    #[test]
+    fn test_resize_zeroed() {
+        let mut bytes: Vector<u8> = Vector::new();
+        bytes.resize_zeroed(1_000_000);
+        assert_eq!(bytes.len(), 1_000_000);
+        assert!(bytes.iter().all(|&b| b == 0));
+
+        let mut floats = Vector::from([1.5f64, 2.5]);
+        floats.resize_zeroed(4);
+        assert_eq!(&*floats, &[1.5, 2.5, 0.0, 0.0]);
+        floats.resize_zeroed(1);
+        assert_eq!(&*floats, &[1.5]);
+
+        // Matches the generic clone-based resize
+        let mut zeroed: Vector<u32> = Vector::from([7, 8]);
+        let mut cloned = Vector::from([7, 8]);
+        zeroed.resize_zeroed(100);
+        cloned.resize(100, 0);
+        assert_eq!(&*zeroed, &*cloned);
+    }
+
+    #[test]
+    fn test_as_chunks() {
+        let mut vec: Vector<i32> = (0..10).collect();
+
+        let (chunks, rest) = vec.as_chunks::<4>();
+        assert_eq!(chunks, &[[0, 1, 2, 3], [4, 5, 6, 7]]);
+        assert_eq!(rest, &[8, 9]);
+
+        let (chunks, rest) = vec.as_chunks_mut::<4>();
+        for chunk in chunks.iter_mut() {
+            chunk.reverse();
+        }
+        rest[0] = 80;
+        assert_eq!(&*vec, &[3, 2, 1, 0, 7, 6, 5, 4, 80, 9]);
+
+        let (chunks, rest) = vec.as_chunks::<20>();
+        assert!(chunks.is_empty());
+        assert_eq!(rest.len(), 10);
+    }
+
+    #[test]
+    fn test_map() {
+        let vec = Vector::from([1, -2, 30]);
+        let strings: Vector<String> = vec.map(|x| x.to_string());
+        assert_eq!(&*strings, &["1", "-2", "30"]);
+        assert_eq!(strings.capacity(), 3);
+        assert_eq!(&*vec, &[1, -2, 30]);
+
+        assert!(Vector::<i32>::new().map(|x| x + 1).is_empty());
+    }
+
+    #[test]
+    fn test_contains() {
+        let vec = Vector::from(["a".to_string(), "b".to_string()]);
+        assert!(vec.contains(&"b".to_string()));
+        assert!(!vec.contains(&"c".to_string()));
+        assert!(!Vector::<i32>::new().contains(&0));
+    }
+
+    #[test]
+    fn test_growth_clamped_near_capacity_limit() {
+        // Elements this large leave room for only 255 of them below
+        // `isize::MAX` bytes on any pointer width
+        type Big = [u8; (isize::MAX as usize >> 8) + 1];
+        const HUGE: usize = mem::size_of::<Big>();
+
+        // Hands out dangling pointers for huge blocks, which are never read
+        // or written since the vectors below stay empty
+        struct HugeAllocator;
+
+        unsafe impl Allocator for HugeAllocator {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+                assert!(layout.size() >= HUGE);
+                Ok(NonNull::new(layout.align() as *mut u8).unwrap())
+            }
+
+            unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+
+            unsafe fn grow(
+                &self,
+                ptr: NonNull<u8>,
+                _old_layout: Layout,
+                new_layout: Layout,
+            ) -> Result<NonNull<u8>, AllocError> {
+                assert!(new_layout.size() >= HUGE);
+                Ok(ptr)
+            }
+        }
+
+        let max_capacity = isize::MAX as usize / HUGE;
+        assert_eq!(max_capacity, 255);
+
+        // Growing by 1.5x would pass the limit, so the capacity is clamped
+        let mut vec: Vector<Big, _> = Vector::with_capacity_in(max_capacity - 10, HugeAllocator);
+        vec.reserve(max_capacity - 9);
+        assert_eq!(vec.capacity(), max_capacity);
+
+        // Nothing larger fits at all
+        assert_eq!(vec.try_reserve(max_capacity + 1), Err(TryReserveError::CapacityOverflow));
+        assert_eq!(vec.capacity(), max_capacity);
+        assert_eq!(
+            Vector::<Big, _>::new_in(HugeAllocator).try_reserve(usize::MAX),
+            Err(TryReserveError::CapacityOverflow)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn test_reserve_past_capacity_limit() {
+        let mut vec: Vector<u64> = Vector::new();
+        vec.reserve(isize::MAX as usize / 8 + 1);
+    }
+
+    #[test]
+    fn test_freeze_shared_across_threads() {
+        let vec: Vector<String> = (0..100).map(|i| i.to_string()).collect();
+        let frozen = vec.freeze();
+
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let frozen = Arc::clone(&frozen);
+                std::thread::spawn(move || frozen.iter().skip(t).step_by(4).map(|s| s.len()).sum::<usize>())
+            })
+            .collect();
+        let total: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+
+        assert_eq!(total, frozen.iter().map(String::len).sum::<usize>());
+        assert_eq!(frozen.len(), 100);
+        assert_eq!(frozen[42], "42");
+        assert_eq!(Arc::strong_count(&frozen), 1);
+        assert!(Vector::<u8>::new().freeze().is_empty());
+    }
+
+    #[test]
+    fn test_into_linked_list() {
+        let list = Vector::from([1, 2, 3]).into_linked_list();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+        // Elements are moved, never cloned or dropped twice
+        use std::rc::Rc;
+        let tracker = Rc::new(());
+        let vec: Vector<Rc<()>> = (0..4).map(|_| Rc::clone(&tracker)).collect();
+        let list = vec.into_linked_list();
+        assert_eq!(Rc::strong_count(&tracker), 5);
+        drop(list);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    fn test_into_array_chunks() {
+        let vec: Vector<u8> = (0..10).collect();
+        let mut chunks = vec.into_array_chunks::<4>();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.remainder(), &[8, 9]);
+
+        assert_eq!(chunks.next(), Some([0, 1, 2, 3]));
+        assert_eq!(chunks.next(), Some([4, 5, 6, 7]));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.remainder(), &[8, 9]);
+
+        let mut exact = Vector::from([1, 2, 3, 4]).into_array_chunks::<2>();
+        assert_eq!(exact.by_ref().count(), 2);
+        assert!(exact.remainder().is_empty());
+    }
+
+    #[test]
+    fn test_into_array_chunks_drops_unyielded() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let vec: Vector<Rc<()>> = (0..7).map(|_| Rc::clone(&tracker)).collect();
+        let mut chunks = vec.into_array_chunks::<3>();
+        let first = chunks.next().unwrap();
+        assert_eq!(Rc::strong_count(&tracker), 8);
+
+        drop(chunks);
+        assert_eq!(Rc::strong_count(&tracker), 4);
+        drop(first);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn test_into_array_chunks_zero() {
+        Vector::from([1]).into_array_chunks::<0>();
+    }
+
+    // Records its value in `DROPPED` when dropped, and panics afterwards if
+    // the value is `PANIC_ON`
+    struct PanicOnDrop(i32);
+
+    const PANIC_ON: i32 = 3;
+
+    std::thread_local! {
+        static DROPPED: std::cell::RefCell<Vec<i32>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    impl Drop for PanicOnDrop {
+        fn drop(&mut self) {
+            DROPPED.with(|dropped| dropped.borrow_mut().push(self.0));
+            if self.0 == PANIC_ON {
+                panic!("dropping {}", self.0);
+            }
+        }
+    }
+
+    fn take_dropped() -> Vec<i32> {
+        DROPPED.with(|dropped| dropped.take())
+    }
+
+    #[test]
+    fn test_retain_panicking_drop() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut vec: Vector<PanicOnDrop> = (1..=6).map(PanicOnDrop).collect();
+        let result = catch_unwind(AssertUnwindSafe(|| vec.retain(|x| x.0 % 2 == 0)));
+        assert!(result.is_err());
+
+        // 1 and 3 were removed; 3's destructor panicked after it had been
+        // counted as gone, and 4..=6 were never visited
+        assert_eq!(take_dropped(), [1, 3]);
+        assert_eq!(vec.iter().map(|x| x.0).collect::<Vec<_>>(), [2, 4, 5, 6]);
+
+        drop(vec);
+        assert_eq!(take_dropped(), [2, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_retain_panicking_predicate() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut vec: Vector<String> = (1..=5).map(|i| i.to_string()).collect();
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            vec.retain(|s| match s.as_str() {
+                "4" => panic!("predicate"),
+                s => s != "2",
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(&*vec, &["1", "3", "4", "5"]);
+    }
+
+    #[test]
+    fn test_truncate_panicking_drop() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut vec: Vector<PanicOnDrop> = (1..=5).map(PanicOnDrop).collect();
+        let result = catch_unwind(AssertUnwindSafe(|| vec.truncate(1)));
+        assert!(result.is_err());
+
+        // The rest of the tail is still dropped after the panic
+        assert_eq!(take_dropped(), [2, 3, 4, 5]);
+        assert_eq!(vec.iter().map(|x| x.0).collect::<Vec<_>>(), [1]);
+
+        drop(vec);
+        assert_eq!(take_dropped(), [1]);
+    }
+}
@@ -0,0 +1,175 @@
+//! Rayon parallel iteration for vectors
+//!
+//! A vector's elements are one contiguous slice, so parallel iterators simply
+//! split that slice. Implementing `IntoParallelIterator` for `&Vector` and
+//! `&mut Vector` also provides `par_iter` and `par_iter_mut` through Rayon's
+//! blanket `IntoParallelRefIterator` and `IntoParallelRefMutIterator` impls.
+
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use rayon::slice::ParallelSliceMut;
+
+use ::alloc::vec::Vec;
+
+use super::Vector;
+use crate::allocator::Allocator;
+
+// Number of elements each parallel task fills in `par_fill_with`, so that
+// cheap closures are not dominated by task overhead
+const FILL_CHUNK_SIZE: usize = 1024;
+
+impl<T: Send, A: Allocator> Vector<T, A> {
+    /// Overwrites every element with `f(index)`, computing them in parallel
+    ///
+    /// The buffer is split into chunks that Rayon fills on its thread pool,
+    /// so `f` must be callable from several threads at once. The previous
+    /// elements are dropped as they are replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::from([0u64; 5]);
+    /// vec.par_fill_with(|i| (i * i) as u64);
+    /// assert_eq!(&*vec, &[0, 1, 4, 9, 16]);
+    /// ```
+    pub fn par_fill_with<F>(&mut self, f: F)
+    where
+        F: Fn(usize) -> T + Sync,
+    {
+        self.par_chunks_mut(FILL_CHUNK_SIZE)
+            .enumerate()
+            .for_each(|(chunk, values)| {
+                let offset = chunk * FILL_CHUNK_SIZE;
+                for (i, value) in values.iter_mut().enumerate() {
+                    *value = f(offset + i);
+                }
+            });
+    }
+}
+
+impl<T: Ord + Send, A: Allocator> Vector<T, A> {
+    /// Sorts the vector in parallel, preserving the order of equal elements
+    ///
+    /// This is Rayon's parallel merge sort over the vector's slice, which
+    /// allocates a buffer of up to `len / 2` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_data_structures::vector::Vector;
+    ///
+    /// let mut vec = Vector::from([5, 1, 4, 2, 3]);
+    /// vec.par_sort();
+    /// assert_eq!(&*vec, &[1, 2, 3, 4, 5]);
+    /// ```
+    pub fn par_sort(&mut self) {
+        ParallelSliceMut::par_sort(&mut **self);
+    }
+
+    /// Sorts the vector in parallel without preserving the order of equal
+    /// elements
+    ///
+    /// This is Rayon's parallel quicksort over the vector's slice; it does
+    /// not allocate.
+    pub fn par_sort_unstable(&mut self) {
+        ParallelSliceMut::par_sort_unstable(&mut **self);
+    }
+}
+
+impl<T: Send> IntoParallelIterator for Vector<T> {
+    type Iter = rayon::vec::IntoIter<T>;
+    type Item = T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        // Handing the buffer over to a `Vec` is free and lets Rayon drain it
+        Vec::from(self).into_par_iter()
+    }
+}
+
+impl<'a, T: Sync + 'a, A: Allocator> IntoParallelIterator for &'a Vector<T, A> {
+    type Iter = rayon::slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        let slice: &'a [T] = self;
+        slice.into_par_iter()
+    }
+}
+
+impl<'a, T: Send + 'a, A: Allocator> IntoParallelIterator for &'a mut Vector<T, A> {
+    type Iter = rayon::slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        let slice: &'a mut [T] = self;
+        slice.into_par_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rayon::prelude::*;
+
+    #[test]
+    fn test_par_iter_sum() {
+        let vec: Vector<u64> = (0..1_000_000).collect::<Vec<_>>().into();
+        let sequential: u64 = vec.iter().sum();
+        let parallel: u64 = vec.par_iter().sum();
+        assert_eq!(parallel, sequential);
+    }
+
+    #[test]
+    fn test_par_iter_mut() {
+        let mut vec: Vector<u64> = (0..10_000).collect::<Vec<_>>().into();
+        vec.par_iter_mut().for_each(|x| *x *= 2);
+        assert!(vec.iter().enumerate().all(|(i, &x)| x == 2 * i as u64));
+    }
+
+    #[test]
+    fn test_into_par_iter() {
+        let vec: Vector<String> = (0..1_000).map(|i| i.to_string()).collect::<Vec<_>>().into();
+        let lengths: Vec<usize> = vec.into_par_iter().map(|s| s.len()).collect();
+        assert_eq!(lengths.len(), 1_000);
+        assert_eq!(lengths[999], 3);
+    }
+
+    #[test]
+    fn test_par_fill_with() {
+        let mut parallel: Vector<u64> = Vector::from(vec![0; 100_000]);
+        parallel.par_fill_with(|i| (i * i) as u64);
+
+        let mut sequential: Vector<u64> = Vector::from(vec![0; 100_000]);
+        for (i, value) in sequential.iter_mut().enumerate() {
+            *value = (i * i) as u64;
+        }
+
+        assert_eq!(&*parallel, &*sequential);
+    }
+
+    #[test]
+    fn test_par_sort() {
+        // A cheap xorshift keeps the input deterministic without extra deps
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let values: Vec<u64> = (0..1_000_000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state % 100_000
+            })
+            .collect();
+
+        let mut sequential = values.clone();
+        sequential.sort();
+
+        let mut stable = Vector::from(values.clone());
+        stable.par_sort();
+        assert_eq!(&*stable, &*sequential);
+
+        let mut unstable = Vector::from(values);
+        unstable.par_sort_unstable();
+        assert_eq!(&*unstable, &*sequential);
+    }
+}